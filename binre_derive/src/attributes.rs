@@ -0,0 +1,182 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Expr, Ident, Result, Type};
+
+/// Endianness override written as `#[binre(little)]` or `#[binre(big)]`.
+#[derive(Clone, Copy)]
+pub enum EndianOverride {
+    Little,
+    Big,
+}
+
+impl EndianOverride {
+    /// Tokens evaluating to the matching `binre::Endianness` variant
+    pub fn to_tokens(self) -> TokenStream {
+        match self {
+            EndianOverride::Little => quote!(::binre::Endianness::Little),
+            EndianOverride::Big => quote!(::binre::Endianness::Big),
+        }
+    }
+}
+
+/// Attributes placed on the struct or enum itself.
+#[derive(Default)]
+pub struct ContainerAttributes {
+    pub endianness: Option<EndianOverride>,
+    pub repr: Option<Type>,
+}
+
+/// Attributes placed on an enum variant.
+#[derive(Default)]
+pub struct VariantAttributes {
+    pub tag: Option<Expr>,
+}
+
+/// Stream adjustments made before a field, in the order they were written.
+pub enum Directive {
+    /// `magic = b"..."` - Bytes validated on read and emitted on write
+    Magic(Expr),
+    /// `pad = N` - Bytes skipped on read and filled on write
+    Pad(Expr),
+    /// `align = N` - Moves the stream to the next `N` byte boundary, filling the gap on write
+    Align(Expr),
+}
+
+/// Attributes placed on a single field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub endianness: Option<EndianOverride>,
+    pub directives: Vec<Directive>,
+    /// `fill = b"..."` - Pattern of the `pad` and `align` padding, verified on read
+    pub fill: Option<Expr>,
+    /// `if = expr` - The field is an `Option` that is only present when `expr` holds
+    pub condition: Option<Expr>,
+    /// `count = expr` - Element count of a `Vec`, or byte length of a `String`
+    pub count: Option<Expr>,
+}
+
+impl ContainerAttributes {
+    /// Collects `#[binre(...)]` options from the container. A `#[repr(int)]` attribute
+    /// is used as the enum tag type when no explicit `#[binre(repr = ...)]` is present.
+    ///
+    /// # Errors
+    ///     syn::Error - Unknown option or malformed value
+    pub fn parse(attributes: &[Attribute]) -> Result<Self> {
+        let mut result = ContainerAttributes::default();
+
+        for attribute in attributes {
+            if attribute.path().is_ident("repr") && result.repr.is_none() {
+                attribute.parse_nested_meta(|meta| {
+                    if let Some(ident) = meta.path.get_ident() {
+                        if is_integer_type(ident) {
+                            result.repr = Some(syn::parse_quote!(#ident));
+                            return Ok(());
+                        }
+                    }
+
+                    // Other hints like `align(8)` or `packed(2)` carry arguments that
+                    // have to be consumed
+                    if meta.input.peek(syn::token::Paren) {
+                        let arguments;
+                        syn::parenthesized!(arguments in meta.input);
+                        arguments.parse::<proc_macro2::TokenStream>()?;
+                    }
+                    Ok(())
+                })?;
+                continue;
+            }
+
+            if !attribute.path().is_ident("binre") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("little") {
+                    result.endianness = Some(EndianOverride::Little);
+                } else if meta.path.is_ident("big") {
+                    result.endianness = Some(EndianOverride::Big);
+                } else if meta.path.is_ident("repr") {
+                    result.repr = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown binre container attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl VariantAttributes {
+    /// Collects `#[binre(...)]` options from an enum variant.
+    ///
+    /// # Errors
+    ///     syn::Error - Unknown option or malformed value
+    pub fn parse(attributes: &[Attribute]) -> Result<Self> {
+        let mut result = VariantAttributes::default();
+
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("binre")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tag") {
+                    result.tag = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown binre variant attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl FieldAttributes {
+    /// Collects `#[binre(...)]` options from a field.
+    ///
+    /// # Errors
+    ///     syn::Error - Unknown option or malformed value
+    pub fn parse(attributes: &[Attribute]) -> Result<Self> {
+        let mut result = FieldAttributes::default();
+
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("binre")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("little") {
+                    result.endianness = Some(EndianOverride::Little);
+                } else if meta.path.is_ident("big") {
+                    result.endianness = Some(EndianOverride::Big);
+                } else if meta.path.is_ident("magic") {
+                    result
+                        .directives
+                        .push(Directive::Magic(meta.value()?.parse()?));
+                } else if meta.path.is_ident("pad") {
+                    result
+                        .directives
+                        .push(Directive::Pad(meta.value()?.parse()?));
+                } else if meta.path.is_ident("align") {
+                    result
+                        .directives
+                        .push(Directive::Align(meta.value()?.parse()?));
+                } else if meta.path.is_ident("fill") {
+                    result.fill = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("if") {
+                    result.condition = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("count") {
+                    result.count = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown binre field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+fn is_integer_type(ident: &Ident) -> bool {
+    matches!(
+        ident.to_string().as_str(),
+        "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128"
+    )
+}
@@ -0,0 +1,207 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, Path, Result, Type};
+
+use crate::attributes::{ContainerAttributes, Directive};
+use crate::{add_trait_bounds, collect_fields, generic_argument, is_type, variant_tags, FieldInfo};
+
+/// Generates the `binre::BinRead` implementation for a struct or enum.
+///
+/// # Errors
+///     syn::Error - Unions, enums without a tag type, or malformed attributes
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let container = ContainerAttributes::parse(&input.attrs)?;
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => read_fields(&syn::parse_quote!(Self), &data.fields)?,
+        Data::Enum(data) => {
+            let repr = container.repr.as_ref().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    name,
+                    "BinRead enums need a tag type: #[binre(repr = u8)] or #[repr(u8)]",
+                )
+            })?;
+            let tags = variant_tags(data)?;
+            let known_tags = tags.clone();
+
+            let arms = data
+                .variants
+                .iter()
+                .zip(tags)
+                .map(|(variant, tag)| {
+                    let ident = &variant.ident;
+                    let constructor =
+                        read_fields(&syn::parse_quote!(Self::#ident), &variant.fields)?;
+                    Ok(quote! {
+                        if __tag == #tag as #repr {
+                            return #constructor;
+                        }
+                    })
+                })
+                .collect::<Result<Vec<TokenStream>>>()?;
+
+            quote! {
+                let __tag = <#repr as ::binre::BinRead>::read_from(__stream, __endianness)?;
+                #(#arms)*
+                let __known: &[#repr] = &[#(#known_tags as #repr),*];
+                let __start = ::binre::BinaryUtils::tell(__stream)?
+                    .saturating_sub(::std::mem::size_of::<#repr>() as u64);
+                Err(::binre::Error::mismatch("Unknown enum tag.", __known, __tag)
+                    .with_offset(__start)
+                    .into())
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "BinRead cannot be derived for unions",
+            ))
+        }
+    };
+
+    let endianness = container
+        .endianness
+        .map(|value| value.to_tokens())
+        .unwrap_or_else(|| quote!(__endianness));
+
+    let generics = add_trait_bounds(input.generics.clone(), quote!(::binre::BinRead));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::binre::BinRead for #name #type_generics #where_clause {
+            fn read_from<__StreamT: ::std::io::Read + ::std::io::Seek>(
+                __stream: &mut __StreamT,
+                __endianness: ::binre::Endianness,
+            ) -> ::std::io::Result<Self> {
+                let __endianness: ::binre::Endianness = #endianness;
+                #body
+            }
+        }
+    })
+}
+
+/// Generates a block that reads `fields` in order and evaluates to
+/// `Ok(constructor { ... })`. Each field is read inside `BinaryUtils::with_context`,
+/// so errors carry the field name and the offset they surfaced at.
+fn read_fields(constructor: &Path, fields: &Fields) -> Result<TokenStream> {
+    let fields = collect_fields(fields)?;
+
+    let reads = fields
+        .iter()
+        .map(|field| {
+            let binding = &field.binding;
+            let ty = &field.ty;
+            let endianness = field
+                .attributes
+                .endianness
+                .map(|value| value.to_tokens())
+                .unwrap_or_else(|| quote!(__endianness));
+
+            let fill = field.attributes.fill.as_ref();
+            let directives = field
+                .attributes
+                .directives
+                .iter()
+                .map(|directive| read_directive(directive, fill));
+
+            let value = match &field.attributes.condition {
+                Some(condition) => {
+                    let inner = generic_argument(ty, "Option").ok_or_else(|| {
+                        syn::Error::new_spanned(ty, "`if` fields must be an Option<T>")
+                    })?;
+                    let value = read_value(inner, field.attributes.count.as_ref(), &endianness)?;
+                    quote! {
+                        if #condition {
+                            ::std::option::Option::Some(#value)
+                        } else {
+                            ::std::option::Option::None
+                        }
+                    }
+                }
+                None => read_value(ty, field.attributes.count.as_ref(), &endianness)?,
+            };
+
+            let name = field.path_name();
+
+            Ok(quote! {
+                let #binding: #ty = ::binre::BinaryUtils::with_context(__stream, #name, |__stream| {
+                    #(#directives)*
+                    let __value: #ty = #value;
+                    Ok(__value)
+                })?;
+            })
+        })
+        .collect::<Result<Vec<TokenStream>>>()?;
+
+    let members = fields.iter().map(FieldInfo::member_binding);
+
+    Ok(quote! {
+        {
+            #(#reads)*
+            Ok(#constructor { #(#members),* })
+        }
+    })
+}
+
+/// Generates an expression reading a single value of type `ty`.
+///
+/// # Errors
+///     syn::Error - `count` was given for a type other than `Vec<T>` or `String`
+fn read_value(ty: &Type, count: Option<&Expr>, endianness: &TokenStream) -> Result<TokenStream> {
+    let Some(count) = count else {
+        return Ok(quote!(<#ty as ::binre::BinRead>::read_from(__stream, #endianness)?));
+    };
+
+    if let Some(element) = generic_argument(ty, "Vec") {
+        Ok(quote! {
+            ::binre::BinaryReader::read_vec::<#element>(__stream, (#count) as usize, #endianness)?
+        })
+    } else if is_type(ty, "String") {
+        Ok(quote!(::binre::BinaryReader::read_fixed_size_string(__stream, (#count) as usize)?))
+    } else {
+        Err(syn::Error::new_spanned(
+            ty,
+            "`count` fields must be a Vec<T> or a String",
+        ))
+    }
+}
+
+/// Generates the statement applying a `magic`, `pad` or `align` directive on read.
+/// Padding is only verified when the field has a `fill` pattern.
+fn read_directive(directive: &Directive, fill: Option<&Expr>) -> TokenStream {
+    match (directive, fill) {
+        (Directive::Magic(magic), _) => quote! {
+            {
+                let __magic: &[u8] = #magic;
+                let __found = ::binre::BinaryReader::read_raw(__stream, __magic.len())?;
+                if *__found != *__magic {
+                    let __start = ::binre::BinaryUtils::tell(__stream)?
+                        .saturating_sub(__magic.len() as u64);
+                    return Err(::binre::Error::mismatch("Magic value mismatch.", __magic, &*__found)
+                        .with_offset(__start)
+                        .into());
+                }
+            }
+        },
+        (Directive::Pad(length), None) => quote! {
+            ::binre::BinaryUtils::ignore(__stream, (#length) as usize)?;
+        },
+        (Directive::Pad(length), Some(fill)) => quote! {
+            {
+                let __fill: &[u8] = #fill;
+                ::binre::BinaryReader::skip_padding(__stream, (#length) as usize, __fill)?;
+            }
+        },
+        (Directive::Align(alignment), None) => quote! {
+            ::binre::BinaryUtils::next_alignment(__stream, (#alignment) as u64)?;
+        },
+        (Directive::Align(alignment), Some(fill)) => quote! {
+            {
+                let __fill: &[u8] = #fill;
+                ::binre::BinaryReader::skip_to_alignment(__stream, (#alignment) as u64, __fill)?;
+            }
+        },
+    }
+}
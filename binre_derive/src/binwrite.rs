@@ -0,0 +1,337 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, Ident, Path, Result, Type};
+
+use crate::attributes::{ContainerAttributes, Directive};
+use crate::{add_trait_bounds, collect_fields, generic_argument, is_type, variant_tags, FieldInfo};
+
+/// Generates the `binre::BinWrite` implementation for a struct or enum.
+///
+/// # Errors
+///     syn::Error - Unions, enums without a tag type, or malformed attributes
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let container = ContainerAttributes::parse(&input.attrs)?;
+    let name = &input.ident;
+
+    let body = write_body(input, &container, false)?;
+    let seekable_body = write_body(input, &container, true)?;
+
+    let endianness = container
+        .endianness
+        .map(|value| value.to_tokens())
+        .unwrap_or_else(|| quote!(__endianness));
+
+    let generics = add_trait_bounds(input.generics.clone(), quote!(::binre::BinWrite));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::binre::BinWrite for #name #type_generics #where_clause {
+            fn write_to<__StreamT: ::std::io::Write>(
+                &self,
+                __stream: &mut __StreamT,
+                __endianness: ::binre::Endianness,
+            ) -> ::std::io::Result<()> {
+                let __endianness: ::binre::Endianness = #endianness;
+                #body
+                Ok(())
+            }
+
+            fn write_to_seekable<__StreamT: ::std::io::Write + ::std::io::Seek>(
+                &self,
+                __stream: &mut __StreamT,
+                __endianness: ::binre::Endianness,
+            ) -> ::std::io::Result<()> {
+                let __endianness: ::binre::Endianness = #endianness;
+                #seekable_body
+                Ok(())
+            }
+        }
+    })
+}
+
+/// Generates the match writing `self`, for `write_to_seekable` when `seekable` is set
+/// and for `write_to` otherwise.
+///
+/// # Errors
+///     syn::Error - Unions, enums without a tag type, or malformed attributes
+fn write_body(input: &DeriveInput, container: &ContainerAttributes, seekable: bool) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let arm = write_fields(&syn::parse_quote!(Self), &data.fields, None, seekable)?;
+            quote! {
+                match self {
+                    #arm
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let repr = container.repr.as_ref().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    name,
+                    "BinWrite enums need a tag type: #[binre(repr = u8)] or #[repr(u8)]",
+                )
+            })?;
+            let tags = variant_tags(data)?;
+
+            let arms = data
+                .variants
+                .iter()
+                .zip(tags)
+                .map(|(variant, tag)| {
+                    let ident = &variant.ident;
+                    let tag_write = quote! {
+                        ::binre::BinWrite::write_to(&(#tag as #repr), __stream, __endianness)?;
+                    };
+                    write_fields(
+                        &syn::parse_quote!(Self::#ident),
+                        &variant.fields,
+                        Some(tag_write),
+                        seekable,
+                    )
+                })
+                .collect::<Result<Vec<TokenStream>>>()?;
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "BinWrite cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(body)
+}
+
+/// Generates a match arm that destructures `path` and writes its fields in order,
+/// preceded by `prefix` (the enum tag) when given. Errors carry the field name: when
+/// `seekable` each field is written inside `BinaryUtils::with_context`, which also
+/// records the offset, otherwise through `binre::Error::add_context`.
+fn write_fields(
+    path: &Path,
+    fields: &Fields,
+    prefix: Option<TokenStream>,
+    seekable: bool,
+) -> Result<TokenStream> {
+    let fields = collect_fields(fields)?;
+
+    let members = fields.iter().map(FieldInfo::member_binding);
+
+    let writes = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let binding = &field.binding;
+            let ty = &field.ty;
+            let endianness = field
+                .attributes
+                .endianness
+                .map(|value| value.to_tokens())
+                .unwrap_or_else(|| quote!(__endianness));
+
+            let fill = field.attributes.fill.as_ref();
+            let directives = field
+                .attributes
+                .directives
+                .iter()
+                .map(|directive| write_directive(directive, fill, seekable));
+
+            let count = field
+                .attributes
+                .count
+                .as_ref()
+                .map(|count| count_value(count, &fields[..index]));
+
+            let value = match &field.attributes.condition {
+                Some(condition) => {
+                    let inner = generic_argument(ty, "Option").ok_or_else(|| {
+                        syn::Error::new_spanned(ty, "`if` fields must be an Option<T>")
+                    })?;
+                    let value =
+                        write_value(&quote!(__value), inner, count.as_ref(), &endianness, seekable)?;
+                    let condition = earlier_value(condition, &fields[..index]);
+                    quote! {
+                        let __condition: bool = #condition;
+                        match (__condition, #binding) {
+                            (true, ::std::option::Option::Some(__value)) => {
+                                #value
+                            }
+                            (false, ::std::option::Option::None) => {}
+                            _ => {
+                                return Err(::binre::Error::new(
+                                    ::std::io::ErrorKind::InvalidInput,
+                                    "Option does not match the if condition.",
+                                )
+                                .into());
+                            }
+                        }
+                    }
+                }
+                None => write_value(&quote!(#binding), ty, count.as_ref(), &endianness, seekable)?,
+            };
+
+            let name = field.path_name();
+
+            if seekable {
+                return Ok(quote! {
+                    ::binre::BinaryUtils::with_context(__stream, #name, |__stream| {
+                        #(#directives)*
+                        #value
+                        Ok(())
+                    })?;
+                });
+            }
+
+            Ok(quote! {
+                ::binre::Error::add_context(
+                    (|| -> ::std::io::Result<()> {
+                        #(#directives)*
+                        #value
+                        Ok(())
+                    })(),
+                    #name,
+                )?;
+            })
+        })
+        .collect::<Result<Vec<TokenStream>>>()?;
+
+    Ok(quote! {
+        #path { #(#members),* } => {
+            #prefix
+            #(#writes)*
+        }
+    })
+}
+
+/// Generates the statement writing the value referenced by `value`, of type `ty`.
+/// Counted values first check that their length matches `count`, so the size field
+/// written earlier always agrees with the data.
+///
+/// # Errors
+///     syn::Error - `count` was given for a type other than `Vec<T>` or `String`
+fn write_value(
+    value: &TokenStream,
+    ty: &Type,
+    count: Option<&TokenStream>,
+    endianness: &TokenStream,
+    seekable: bool,
+) -> Result<TokenStream> {
+    let write = if seekable {
+        quote!(::binre::BinWrite::write_to_seekable)
+    } else {
+        quote!(::binre::BinWrite::write_to)
+    };
+
+    let Some(count) = count else {
+        return Ok(quote!(#write(#value, __stream, #endianness)?;));
+    };
+
+    let count_check = quote! {
+        if #value.len() != #count {
+            return Err(::binre::Error::new(
+                ::std::io::ErrorKind::InvalidInput,
+                "Length does not match the count field.",
+            )
+            .into());
+        }
+    };
+
+    if generic_argument(ty, "Vec").is_some() {
+        Ok(quote! {
+            #count_check
+            for __element in #value.iter() {
+                #write(__element, __stream, #endianness)?;
+            }
+        })
+    } else if is_type(ty, "String") {
+        Ok(quote! {
+            #count_check
+            ::binre::BinaryWriter::write_string(__stream, #value)?;
+        })
+    } else {
+        Err(syn::Error::new_spanned(
+            ty,
+            "`count` fields must be a Vec<T> or a String",
+        ))
+    }
+}
+
+/// Generates the statement applying a `magic`, `pad` or `align` directive on write.
+/// Padding repeats the field's `fill` pattern, or is zeroed without one. Alignment
+/// depends on the position, so without `seekable` it is refused.
+fn write_directive(directive: &Directive, fill: Option<&Expr>, seekable: bool) -> TokenStream {
+    let fill = fill.map_or_else(|| quote!(&[0u8]), |fill| quote!(#fill));
+
+    match directive {
+        Directive::Magic(magic) => quote! {
+            {
+                let __magic: &[u8] = #magic;
+                ::std::io::Write::write_all(__stream, __magic)?;
+            }
+        },
+        Directive::Pad(length) => quote! {
+            {
+                let __fill: &[u8] = #fill;
+                ::binre::BinaryWriter::write_padding(__stream, (#length) as usize, __fill)?;
+            }
+        },
+        Directive::Align(_) if !seekable => quote! {
+            ::std::result::Result::<(), _>::Err(::std::io::Error::new(
+                ::std::io::ErrorKind::Unsupported,
+                "Alignment needs a seekable stream, use write_to_seekable.",
+            ))?;
+        },
+        Directive::Align(alignment) => quote! {
+            {
+                let __fill: &[u8] = #fill;
+                ::binre::SeekableWriter::pad_to_alignment(__stream, (#alignment) as u64, __fill)?;
+            }
+        },
+    }
+}
+
+/// Generates a block evaluating the `count` expression to a `usize`.
+fn count_value(count: &Expr, earlier: &[FieldInfo]) -> TokenStream {
+    let count = earlier_value(count, earlier);
+    quote!((#count) as usize)
+}
+
+/// Generates a block evaluating `expression`, which may refer to `earlier` fields.
+/// Those are bound by reference in the match arm, so the ones the expression refers
+/// to are cloned back into values first, matching what the read side sees.
+fn earlier_value(expression: &Expr, earlier: &[FieldInfo]) -> TokenStream {
+    let mut referenced: Vec<&Ident> = Vec::new();
+    collect_idents(quote!(#expression), &mut |ident| {
+        if let Some(field) = earlier.iter().find(|field| field.binding == ident) {
+            if !referenced.contains(&&field.binding) {
+                referenced.push(&field.binding);
+            }
+        }
+    });
+
+    quote! {
+        {
+            #(let #referenced = ::std::clone::Clone::clone(#referenced);)*
+            #expression
+        }
+    }
+}
+
+/// Calls `visit` with every identifier in `tokens`, including inside groups.
+fn collect_idents(tokens: TokenStream, visit: &mut impl FnMut(Ident)) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => visit(ident),
+            TokenTree::Group(group) => collect_idents(group.stream(), visit),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Derive macros for the `binre` crate.
+//!
+//! `#[derive(BinRead)]` and `#[derive(BinWrite)]` generate implementations of
+//! `binre::BinRead` and `binre::BinWrite` from the field declarations of a struct
+//! or enum, so a single declaration drives both parsing and serialization.
+//!
+//! # Container attributes
+//!     `#[binre(little)]` / `#[binre(big)]` - Force the endianness of every field
+//!     `#[binre(repr = u16)]`                - Integer type of an enum's tag (`#[repr(u16)]` also works)
+//!
+//! # Variant attributes
+//!     `#[binre(tag = 0x10)]` - Tag value of the variant (defaults to its discriminant)
+//!
+//! # Field attributes
+//!     `#[binre(little)]` / `#[binre(big)]` - Force the endianness of this field
+//!     `#[binre(magic = b"BIN\0")]`         - Bytes before the field, validated on read
+//!     `#[binre(pad = 2)]`                   - Bytes before the field, skipped on read and zeroed on write
+//!     `#[binre(align = 4)]`                 - Align the stream before the field, zero filling the gap on write.
+//!                                             Writing needs `write_to_seekable` (EG
+//!                                             `SeekableWriter::write_value_seekable`)
+//!     `#[binre(fill = b"\xCD")]`            - Repeated instead of zeros in `pad`/`align` padding, verified on read
+//!     `#[binre(if = version >= 2)]`         - `Option<T>` field only present when the expression holds
+//!     `#[binre(count = uri_count)]`         - Element count of a `Vec<T>` or byte length of a `String`
+//!
+//! `magic`, `pad` and `align` are applied in the order they are written. Expressions
+//! in `if` and `count` may refer to any earlier field by name (`field_N` for tuple
+//! fields). On write, an `Option` that is `Some` when its `if` is false (or `None`
+//! when it is true), and a `Vec` or `String` whose length differs from its `count`,
+//! are refused with `ErrorKind::InvalidInput`.
+//!
+//! Every field is read and written inside `binre::BinaryUtils::with_context`, so a
+//! failure surfaces as a `binre::Error` naming the field path and the offset it surfaced at.
+//! `BinWrite::write_to` only needs `Write`: its errors name the field path without an
+//! offset, and `align` is refused with `ErrorKind::Unsupported`, also on streams that
+//! can seek. `write_to_seekable` supports everything; `BinaryWriter::write_value`
+//! uses the former and `SeekableWriter::write_value_seekable` the latter.
+mod attributes;
+mod binread;
+mod binwrite;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, DataEnum, DeriveInput, Fields, GenericArgument, Generics,
+    Ident, Member, PathArguments, Result, Type,
+};
+
+use attributes::{FieldAttributes, VariantAttributes};
+
+/// Derives `binre::BinRead`, reading every field in declaration order.
+#[proc_macro_derive(BinRead, attributes(binre))]
+pub fn derive_bin_read(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    binread::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `binre::BinWrite`, writing every field in declaration order.
+#[proc_macro_derive(BinWrite, attributes(binre))]
+pub fn derive_bin_write(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    binwrite::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field of a struct or enum variant along with the local variable it is bound to
+/// in the generated code. Named fields bind to their own name so attribute
+/// expressions can refer to earlier fields; tuple fields bind to `field_N`.
+pub(crate) struct FieldInfo {
+    pub binding: Ident,
+    pub member: Member,
+    pub ty: Type,
+    pub attributes: FieldAttributes,
+}
+
+impl FieldInfo {
+    /// The `member: binding` pair used in constructors and patterns. Named fields use
+    /// the shorthand form since they are bound to their own name.
+    pub fn member_binding(&self) -> TokenStream2 {
+        let binding = &self.binding;
+        match &self.member {
+            Member::Named(_) => quote!(#binding),
+            Member::Unnamed(index) => quote!(#index: #binding),
+        }
+    }
+
+    /// The name used for the field in error paths: its identifier, or its index for
+    /// tuple fields
+    pub fn path_name(&self) -> String {
+        match &self.member {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+}
+
+/// Collects the fields of a struct or variant in declaration order.
+///
+/// # Errors
+///     syn::Error - A field carries a malformed `#[binre(...)]` attribute
+pub(crate) fn collect_fields(fields: &Fields) -> Result<Vec<FieldInfo>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (binding, member) = match &field.ident {
+                Some(ident) => (ident.clone(), Member::Named(ident.clone())),
+                None => (
+                    Ident::new(&format!("field_{index}"), Span::call_site()),
+                    Member::Unnamed(index.into()),
+                ),
+            };
+
+            Ok(FieldInfo {
+                binding,
+                member,
+                ty: field.ty.clone(),
+                attributes: FieldAttributes::parse(&field.attrs)?,
+            })
+        })
+        .collect()
+}
+
+/// Computes the tag expression of every variant of an enum. Variants without a
+/// `#[binre(tag = ...)]` use their explicit discriminant, or the previous tag plus one.
+///
+/// # Errors
+///     syn::Error - A variant carries a malformed `#[binre(...)]` attribute
+pub(crate) fn variant_tags(data: &DataEnum) -> Result<Vec<TokenStream2>> {
+    let mut tags: Vec<TokenStream2> = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        let attributes = VariantAttributes::parse(&variant.attrs)?;
+
+        let tag = match (attributes.tag, &variant.discriminant) {
+            (Some(tag), _) => quote!((#tag)),
+            (None, Some((_, discriminant))) => quote!((#discriminant)),
+            (None, None) => match tags.last() {
+                Some(previous) => quote!((#previous + 1)),
+                None => quote!((0)),
+            },
+        };
+        tags.push(tag);
+    }
+
+    Ok(tags)
+}
+
+/// Returns `T` when `ty` is written as `wrapper<T>` (e.g. `Option<T>`, `Vec<T>`).
+pub(crate) fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `ty` is written as `name` without generic arguments (e.g. `String`).
+pub(crate) fn is_type(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name && segment.arguments.is_none()),
+        _ => false,
+    }
+}
+
+/// Adds `bound` to every type parameter of the deriving type.
+pub(crate) fn add_trait_bounds(mut generics: Generics, bound: TokenStream2) -> Generics {
+    for parameter in generics.type_params_mut() {
+        parameter.bounds.push(syn::parse_quote!(#bound));
+    }
+    generics
+}
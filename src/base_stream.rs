@@ -0,0 +1,144 @@
+use std::io::{self, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+/// A stream that keeps a stack of base offsets.
+///
+/// Positions are reported and seeked relative to the innermost base, so everything
+/// built on `Seek` honours it: `BinaryUtils::tell`, `next_alignment` and
+/// `read_at_offset`, `FilePtr` offsets, and `SeekableWriter` alignment and patching.
+/// An embedded sub-file read or written after `push_base` behaves exactly as it does
+/// standalone.
+///
+/// Placeholders reserved inside a base must be patched before that base is popped.
+/// Moving the inner stream before the base through `get_mut` makes seeking and
+/// `tell` fail with `ErrorKind::InvalidInput`.
+pub struct BaseStream<StreamT> {
+    stream: StreamT,
+    bases: Vec<u64>,
+}
+
+impl<StreamT> BaseStream<StreamT> {
+    pub fn new(stream: StreamT) -> Self {
+        BaseStream {
+            stream,
+            bases: Vec::new(),
+        }
+    }
+
+    /// Absolute position of the innermost base (zero when none was pushed)
+    pub fn base(&self) -> u64 {
+        self.bases.last().copied().unwrap_or(0)
+    }
+
+    /// Number of bases currently pushed
+    pub fn depth(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Remove the innermost base
+    ///
+    /// # Returns
+    ///     The absolute position of the removed base, None when none was pushed
+    pub fn pop_base(&mut self) -> Option<u64> {
+        self.bases.pop()
+    }
+
+    pub fn get_ref(&self) -> &StreamT {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut StreamT {
+        &mut self.stream
+    }
+
+    pub fn into_inner(self) -> StreamT {
+        self.stream
+    }
+}
+
+impl<StreamT: Seek> BaseStream<StreamT> {
+    /// Make the current position the new base, so it now reads as position zero
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from seeking
+    pub fn push_base(&mut self) -> io::Result<()> {
+        let position = self.stream.stream_position()?;
+        self.bases.push(position);
+
+        Ok(())
+    }
+
+    /// Run `scope` with the current position as the base, then pop it again (also
+    /// when `scope` returns an error)
+    ///
+    /// # Parameters
+    ///     `scope` - Closure receiving the stream
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from seeking or `scope`
+    ///
+    /// # Returns
+    ///     The value returned by `scope`
+    pub fn with_base<T>(
+        &mut self,
+        scope: impl FnOnce(&mut Self) -> io::Result<T>,
+    ) -> io::Result<T> {
+        self.push_base()?;
+        let result = scope(self);
+        self.pop_base();
+
+        result
+    }
+}
+
+impl<StreamT: Read> Read for BaseStream<StreamT> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.read(buf)
+    }
+}
+
+impl<StreamT: Write> Write for BaseStream<StreamT> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Positions are relative to the innermost base; seeking before it is an error and
+/// leaves the position unchanged
+impl<StreamT: Seek> Seek for BaseStream<StreamT> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let base = self.base();
+
+        let absolute = match position {
+            SeekFrom::Start(offset) => {
+                let target = base.checked_add(offset).ok_or(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Invalid seek to an overflowing position.",
+                ))?;
+                self.stream.seek(SeekFrom::Start(target))?
+            }
+            _ if base == 0 => self.stream.seek(position)?,
+            _ => {
+                let previous = self.stream.stream_position()?;
+                let absolute = self.stream.seek(position)?;
+                if absolute < base {
+                    self.stream.seek(SeekFrom::Start(previous))?;
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Invalid seek to a position before the base.",
+                    ));
+                }
+                absolute
+            }
+        };
+
+        // The inner stream may have been moved before the base through `get_mut`
+        absolute.checked_sub(base).ok_or(Error::new(
+            ErrorKind::InvalidInput,
+            "Stream position is before the base.",
+        ))
+    }
+}
@@ -1,12 +1,54 @@
-use std::{io::{Error, ErrorKind, Read}, sync::Arc};
+use std::{io::{Error, ErrorKind, Read, Seek}, sync::Arc};
 
-use num_traits::PrimInt;
+use half::{bf16, f16};
+use num_traits::{FromBytes, PrimInt};
 
+use super::binary_traits::BinRead;
+use super::binary_utils::BinaryUtils;
+use super::endian_types::{as_bytes_mut, swap_slice_inplace, PlainData};
 use super::endianness::{swap_inplace, Endianness, SYS_ENDIANNESS};
+use super::error;
+use super::length_unit::LengthUnit;
+use super::read_limits::{announce_allocation, AllocationKind};
+use super::text_encoding::Encoding;
 
 pub trait BinaryReader: Read  {
     fn read_raw(&mut self, size: usize) -> Result<Arc<[u8]>, Error>;
     fn read_to_delim(&mut self, delim: u8) -> Result<Arc<[u8]>, Error>;
+    fn discard(&mut self, length: usize) -> Result<(), Error>;
+
+    fn read_value<T: BinRead>(&mut self, endianness: Endianness) -> Result<T, Error>
+    where
+        Self: Seek + Sized;
+    fn read_vec<T: BinRead>(&mut self, count: usize, endianness: Endianness) -> Result<Vec<T>, Error>
+    where
+        Self: Seek + Sized;
+    fn read_size_prefixed_vec<PrefixT: PrimInt, T: BinRead>(
+        &mut self,
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> Result<Vec<T>, Error>
+    where
+        Self: Seek + Sized;
+
+    fn peek_raw(&mut self, size: usize) -> Result<Arc<[u8]>, Error>
+    where
+        Self: Seek + Sized;
+    fn peek_value<T: BinRead>(&mut self, endianness: Endianness) -> Result<T, Error>
+    where
+        Self: Seek + Sized;
+    fn peek_u8(&mut self) -> Result<u8, Error>
+    where
+        Self: Seek + Sized;
+    fn peek_u16(&mut self, endianness: Endianness) -> Result<u16, Error>
+    where
+        Self: Seek + Sized;
+    fn peek_u32(&mut self, endianness: Endianness) -> Result<u32, Error>
+    where
+        Self: Seek + Sized;
+    fn peek_u64(&mut self, endianness: Endianness) -> Result<u64, Error>
+    where
+        Self: Seek + Sized;
 
     fn read_u8(&mut self) -> Result<u8, Error>;
     fn read_into_u8(&mut self, target: &mut u8) -> Result<(), Error>;
@@ -68,6 +110,60 @@ pub trait BinaryReader: Read  {
     fn read_into_be_i64(&mut self, target: &mut i64) -> Result<(), Error>;
     fn read_into_ne_i64(&mut self, target: &mut i64) -> Result<(), Error>;
 
+    fn read_u24(&mut self, endianness: Endianness) -> Result<u32, Error>;
+    fn read_le_u24(&mut self) -> Result<u32, Error>;
+    fn read_be_u24(&mut self) -> Result<u32, Error>;
+    fn read_ne_u24(&mut self) -> Result<u32, Error>;
+    fn read_into_u24(&mut self, target: &mut u32, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_u24(&mut self, target: &mut u32) -> Result<(), Error>;
+    fn read_into_be_u24(&mut self, target: &mut u32) -> Result<(), Error>;
+    fn read_into_ne_u24(&mut self, target: &mut u32) -> Result<(), Error>;
+
+    fn read_i24(&mut self, endianness: Endianness) -> Result<i32, Error>;
+    fn read_le_i24(&mut self) -> Result<i32, Error>;
+    fn read_be_i24(&mut self) -> Result<i32, Error>;
+    fn read_ne_i24(&mut self) -> Result<i32, Error>;
+    fn read_into_i24(&mut self, target: &mut i32, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_i24(&mut self, target: &mut i32) -> Result<(), Error>;
+    fn read_into_be_i24(&mut self, target: &mut i32) -> Result<(), Error>;
+    fn read_into_ne_i24(&mut self, target: &mut i32) -> Result<(), Error>;
+
+    fn read_u48(&mut self, endianness: Endianness) -> Result<u64, Error>;
+    fn read_le_u48(&mut self) -> Result<u64, Error>;
+    fn read_be_u48(&mut self) -> Result<u64, Error>;
+    fn read_ne_u48(&mut self) -> Result<u64, Error>;
+    fn read_into_u48(&mut self, target: &mut u64, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_u48(&mut self, target: &mut u64) -> Result<(), Error>;
+    fn read_into_be_u48(&mut self, target: &mut u64) -> Result<(), Error>;
+    fn read_into_ne_u48(&mut self, target: &mut u64) -> Result<(), Error>;
+
+    fn read_i48(&mut self, endianness: Endianness) -> Result<i64, Error>;
+    fn read_le_i48(&mut self) -> Result<i64, Error>;
+    fn read_be_i48(&mut self) -> Result<i64, Error>;
+    fn read_ne_i48(&mut self) -> Result<i64, Error>;
+    fn read_into_i48(&mut self, target: &mut i64, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_i48(&mut self, target: &mut i64) -> Result<(), Error>;
+    fn read_into_be_i48(&mut self, target: &mut i64) -> Result<(), Error>;
+    fn read_into_ne_i48(&mut self, target: &mut i64) -> Result<(), Error>;
+
+    fn read_u128(&mut self, endianness: Endianness) -> Result<u128, Error>;
+    fn read_le_u128(&mut self) -> Result<u128, Error>;
+    fn read_be_u128(&mut self) -> Result<u128, Error>;
+    fn read_ne_u128(&mut self) -> Result<u128, Error>;
+    fn read_into_u128(&mut self, target: &mut u128, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_u128(&mut self, target: &mut u128) -> Result<(), Error>;
+    fn read_into_be_u128(&mut self, target: &mut u128) -> Result<(), Error>;
+    fn read_into_ne_u128(&mut self, target: &mut u128) -> Result<(), Error>;
+
+    fn read_i128(&mut self, endianness: Endianness) -> Result<i128, Error>;
+    fn read_le_i128(&mut self) -> Result<i128, Error>;
+    fn read_be_i128(&mut self) -> Result<i128, Error>;
+    fn read_ne_i128(&mut self) -> Result<i128, Error>;
+    fn read_into_i128(&mut self, target: &mut i128, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_i128(&mut self, target: &mut i128) -> Result<(), Error>;
+    fn read_into_be_i128(&mut self, target: &mut i128) -> Result<(), Error>;
+    fn read_into_ne_i128(&mut self, target: &mut i128) -> Result<(), Error>;
+
     fn read_f32(&mut self, endianness: Endianness) -> Result<f32, Error>;
     fn read_le_f32(&mut self) -> Result<f32, Error>;
     fn read_be_f32(&mut self) -> Result<f32, Error>;
@@ -86,6 +182,107 @@ pub trait BinaryReader: Read  {
     fn read_into_be_f64(&mut self, target: &mut f64) -> Result<(), Error>;
     fn read_into_ne_f64(&mut self, target: &mut f64) -> Result<(), Error>;
 
+    fn read_f16(&mut self, endianness: Endianness) -> Result<f16, Error>;
+    fn read_le_f16(&mut self) -> Result<f16, Error>;
+    fn read_be_f16(&mut self) -> Result<f16, Error>;
+    fn read_ne_f16(&mut self) -> Result<f16, Error>;
+    fn read_into_f16(&mut self, target: &mut f16, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_f16(&mut self, target: &mut f16) -> Result<(), Error>;
+    fn read_into_be_f16(&mut self, target: &mut f16) -> Result<(), Error>;
+    fn read_into_ne_f16(&mut self, target: &mut f16) -> Result<(), Error>;
+
+    fn read_bf16(&mut self, endianness: Endianness) -> Result<bf16, Error>;
+    fn read_le_bf16(&mut self) -> Result<bf16, Error>;
+    fn read_be_bf16(&mut self) -> Result<bf16, Error>;
+    fn read_ne_bf16(&mut self) -> Result<bf16, Error>;
+    fn read_into_bf16(&mut self, target: &mut bf16, endianness: Endianness) -> Result<(), Error>;
+    fn read_into_le_bf16(&mut self, target: &mut bf16) -> Result<(), Error>;
+    fn read_into_be_bf16(&mut self, target: &mut bf16) -> Result<(), Error>;
+    fn read_into_ne_bf16(&mut self, target: &mut bf16) -> Result<(), Error>;
+
+    fn read_fixed_point<IntT>(
+        &mut self,
+        fractional_bits: u32,
+        endianness: Endianness,
+    ) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_le_fixed_point<IntT>(&mut self, fractional_bits: u32) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_be_fixed_point<IntT>(&mut self, fractional_bits: u32) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_ne_fixed_point<IntT>(&mut self, fractional_bits: u32) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_into_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+        endianness: Endianness,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_into_le_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_into_be_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+    fn read_into_ne_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default;
+
+    fn read_u8_slice_into(&mut self, target: &mut [u8]) -> Result<(), Error>;
+    fn read_u8_vec(&mut self, count: usize) -> Result<Vec<u8>, Error>;
+    fn read_i8_slice_into(&mut self, target: &mut [i8]) -> Result<(), Error>;
+    fn read_i8_vec(&mut self, count: usize) -> Result<Vec<i8>, Error>;
+    fn read_u16_slice_into(&mut self, target: &mut [u16], endianness: Endianness) -> Result<(), Error>;
+    fn read_u16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u16>, Error>;
+    fn read_i16_slice_into(&mut self, target: &mut [i16], endianness: Endianness) -> Result<(), Error>;
+    fn read_i16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i16>, Error>;
+    fn read_u32_slice_into(&mut self, target: &mut [u32], endianness: Endianness) -> Result<(), Error>;
+    fn read_u32_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u32>, Error>;
+    fn read_i32_slice_into(&mut self, target: &mut [i32], endianness: Endianness) -> Result<(), Error>;
+    fn read_i32_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i32>, Error>;
+    fn read_u64_slice_into(&mut self, target: &mut [u64], endianness: Endianness) -> Result<(), Error>;
+    fn read_u64_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u64>, Error>;
+    fn read_i64_slice_into(&mut self, target: &mut [i64], endianness: Endianness) -> Result<(), Error>;
+    fn read_i64_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i64>, Error>;
+    fn read_u128_slice_into(&mut self, target: &mut [u128], endianness: Endianness) -> Result<(), Error>;
+    fn read_u128_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u128>, Error>;
+    fn read_i128_slice_into(&mut self, target: &mut [i128], endianness: Endianness) -> Result<(), Error>;
+    fn read_i128_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i128>, Error>;
+    fn read_f16_slice_into(&mut self, target: &mut [f16], endianness: Endianness) -> Result<(), Error>;
+    fn read_f16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<f16>, Error>;
+    fn read_bf16_slice_into(&mut self, target: &mut [bf16], endianness: Endianness) -> Result<(), Error>;
+    fn read_bf16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<bf16>, Error>;
+    fn read_f32_slice_into(&mut self, target: &mut [f32], endianness: Endianness) -> Result<(), Error>;
+    fn read_f32_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<f32>, Error>;
+    fn read_f64_slice_into(&mut self, target: &mut [f64], endianness: Endianness) -> Result<(), Error>;
+    fn read_f64_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<f64>, Error>;
+
     fn read_fixed_size_string(&mut self, size: usize) -> Result<String, Error>;
     fn read_into_fixed_size_string(
         &mut self,
@@ -105,6 +302,76 @@ pub trait BinaryReader: Read  {
         target: &mut String,
         endianness: Endianness,
     ) -> Result<(), Error>;
+
+    fn read_size_prefixed_bytes<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+    ) -> Result<Arc<[u8]>, Error>;
+
+    fn read_fixed_size_encoded_string(
+        &mut self,
+        size: usize,
+        encoding: Encoding,
+        lossy: bool,
+    ) -> Result<String, Error>;
+    fn read_null_terminated_encoded_string(
+        &mut self,
+        encoding: Encoding,
+        lossy: bool,
+    ) -> Result<String, Error>;
+    fn read_size_prefixed_encoded_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+        encoding: Encoding,
+        lossy: bool,
+    ) -> Result<String, Error>;
+
+    fn read_uleb128(&mut self, max_length: usize) -> Result<u64, Error>;
+    fn read_sleb128(&mut self, max_length: usize) -> Result<i64, Error>;
+    fn read_zigzag_varint(&mut self, max_length: usize) -> Result<i64, Error>;
+    fn read_vlq(&mut self, max_length: usize) -> Result<u64, Error>;
+    fn read_7bit_encoded_int(&mut self, max_length: usize) -> Result<i32, Error>;
+
+    fn read_fixed_size_utf16_string(
+        &mut self,
+        length: usize,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error>;
+    fn read_null_terminated_utf16_string(
+        &mut self,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error>;
+    fn read_size_prefixed_utf16_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+        unit: LengthUnit,
+        detect_bom: bool,
+    ) -> Result<String, Error>;
+
+    fn read_fixed_size_utf32_string(
+        &mut self,
+        length: usize,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error>;
+    fn read_null_terminated_utf32_string(
+        &mut self,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error>;
+    fn read_size_prefixed_utf32_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+        unit: LengthUnit,
+        detect_bom: bool,
+    ) -> Result<String, Error>;
+
+    fn skip_padding(&mut self, length: usize, fill: &[u8]) -> Result<(), Error>;
+    fn skip_to_alignment(&mut self, alignment: u64, fill: &[u8]) -> Result<(), Error>
+    where
+        Self: Seek + Sized;
 }
 
 impl<StreamT: Read > BinaryReader for StreamT {
@@ -121,12 +388,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Returns
     ///     Arc<[u8]> - The data read from the stream
     fn read_raw(&mut self, size: usize) -> Result<Arc<[u8]>, Error> {
-        let mut buffer = vec![0u8; size];
-        let bytes_read = self.read(&mut buffer)?;
-        if bytes_read != size {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
-        Ok(buffer.into())
+        Ok(read_plain_vec(self, size, *SYS_ENDIANNESS)?.into())
     }
 
     /// Reads data from stream until a delimiter is encountered or EOF reached. 
@@ -141,6 +403,8 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Returns
     ///     Arc<[u8]> - The data read from the stream
     fn read_to_delim(&mut self, delim: u8) -> Result<Arc<[u8]>, Error> {
+        let _allocation = announce_allocation(AllocationKind::Buffer, None);
+
         let mut buffer = Vec::<u8>::new();
         loop {
             let byte = self.read_u8()?;
@@ -152,6 +416,220 @@ impl<StreamT: Read > BinaryReader for StreamT {
         return Ok(buffer.into());
     }
 
+    /// Skips `length` bytes by reading and dropping them. This is the fallback for
+    /// streams that can't seek (EG sockets or decompressors); seekable streams should
+    /// use `BinaryUtils::ignore`, which moves past the bytes without reading them.
+    ///
+    /// # Parameters
+    ///     `length` - Number of bytes to skip
+    ///
+    /// # Errors
+    ///     io::Error                - Error bubbled up from stream read
+    ///     io::Error::UnexpectedEof - The stream ended before `length` bytes were skipped
+    fn discard(&mut self, length: usize) -> Result<(), Error> {
+        let length = length as u64;
+        let skipped = std::io::copy(&mut self.take(length), &mut std::io::sink())?;
+        if skipped != length {
+            return Err(Error::from(ErrorKind::UnexpectedEof));
+        }
+
+        Ok(())
+    }
+
+    /// Read any `BinRead` type from the stream. Named `read_value` rather than `read`
+    /// so it does not clash with `Read::read`.
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the type's `BinRead` implementation reports
+    ///
+    /// # Returns
+    ///     T value read from the stream
+    fn read_value<T: BinRead>(&mut self, endianness: Endianness) -> Result<T, Error>
+    where
+        Self: Seek + Sized,
+    {
+        T::read_from(self, endianness)
+    }
+
+    /// Read `count` consecutive `BinRead` values from the stream. Errors carry the
+    /// index of the failing element.
+    ///
+    /// # Parameters
+    ///     `count`      - Number of elements to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the element's `BinRead` implementation reports
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_vec<T: BinRead>(&mut self, count: usize, endianness: Endianness) -> Result<Vec<T>, Error>
+    where
+        Self: Seek + Sized,
+    {
+        let _allocation =
+            announce_allocation(AllocationKind::Elements, Some(count.saturating_mul(size_of::<T>())));
+
+        // Grows as elements arrive, so a corrupt count fails at the end of the stream
+        let mut values = Vec::new();
+        for index in 0..count {
+            values.push(self.with_context(index, |stream| T::read_from(stream, endianness))?);
+        }
+
+        Ok(values)
+    }
+
+    /// Read a size prefixed sequence of `BinRead` values from the stream. Errors carry
+    /// the index of the failing element.
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the prefix and the elements
+    ///     `unit`       - Whether the prefix counts elements or the bytes they take up
+    ///
+    /// # Errors
+    ///     io::Error                - Whatever the element's `BinRead` implementation reports
+    ///     io::Error::UnexpectedEof - when the prefix is truncated
+    ///     io::Error::InvalidData   - When the prefix is negative, or the elements run past a byte length
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_size_prefixed_vec<PrefixT: PrimInt, T: BinRead>(
+        &mut self,
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> Result<Vec<T>, Error>
+    where
+        Self: Seek + Sized,
+    {
+        let size = read_size_prefix::<Self, PrefixT>(self, endianness)?;
+        if unit == LengthUnit::Elements {
+            return self.read_vec(size, endianness);
+        }
+
+        let _allocation = announce_allocation(AllocationKind::Buffer, Some(size));
+
+        let end = self.tell()?.saturating_add(size as u64);
+        let mut values = Vec::new();
+        while self.tell()? < end {
+            let index = values.len();
+            values.push(self.with_context(index, |stream| T::read_from(stream, endianness))?);
+        }
+
+        if self.tell()? != end {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Elements do not end at the size given by the prefix.",
+            ));
+        }
+
+        Ok(values)
+    }
+
+    // ------------------------------------------------------------------------------- Peek
+    /// Read `size` bytes without advancing the stream
+    ///
+    /// # Errors
+    ///     io::Error                - Error bubbled up from stream read or seeking
+    ///     io::Error::UnexpectedEof - There wasn't enough data in the stream for data
+    ///
+    /// # Returns
+    ///     Arc<[u8]> - The data at the current position
+    fn peek_raw(&mut self, size: usize) -> Result<Arc<[u8]>, Error>
+    where
+        Self: Seek + Sized,
+    {
+        self.peek(|stream| stream.read_raw(size))
+    }
+
+    /// Read any `BinRead` type without advancing the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the type's `BinRead` implementation reports, or seeking
+    ///
+    /// # Returns
+    ///     T value at the current position
+    fn peek_value<T: BinRead>(&mut self, endianness: Endianness) -> Result<T, Error>
+    where
+        Self: Seek + Sized,
+    {
+        self.peek(|stream| T::read_from(stream, endianness))
+    }
+
+    /// Read an u8 without advancing the stream
+    ///
+    /// # Errors
+    ///     io::Error                - Error bubbled up from stream read or seeking
+    ///     io::Error::UnexpectedEof - There wasn't enough data in the stream for data
+    ///
+    /// # Returns
+    ///     u8 - The value at the current position
+    fn peek_u8(&mut self) -> Result<u8, Error>
+    where
+        Self: Seek + Sized,
+    {
+        self.peek(|stream| stream.read_u8())
+    }
+
+    /// Read an u16 without advancing the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error                - Error bubbled up from stream read or seeking
+    ///     io::Error::UnexpectedEof - There wasn't enough data in the stream for data
+    ///
+    /// # Returns
+    ///     u16 - The value at the current position
+    fn peek_u16(&mut self, endianness: Endianness) -> Result<u16, Error>
+    where
+        Self: Seek + Sized,
+    {
+        self.peek(|stream| stream.read_u16(endianness))
+    }
+
+    /// Read an u32 without advancing the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error                - Error bubbled up from stream read or seeking
+    ///     io::Error::UnexpectedEof - There wasn't enough data in the stream for data
+    ///
+    /// # Returns
+    ///     u32 - The value at the current position
+    fn peek_u32(&mut self, endianness: Endianness) -> Result<u32, Error>
+    where
+        Self: Seek + Sized,
+    {
+        self.peek(|stream| stream.read_u32(endianness))
+    }
+
+    /// Read an u64 without advancing the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error                - Error bubbled up from stream read or seeking
+    ///     io::Error::UnexpectedEof - There wasn't enough data in the stream for data
+    ///
+    /// # Returns
+    ///     u64 - The value at the current position
+    fn peek_u64(&mut self, endianness: Endianness) -> Result<u64, Error>
+    where
+        Self: Seek + Sized,
+    {
+        self.peek(|stream| stream.read_u64(endianness))
+    }
+
     // ------------------------------------------------------------------------------- u8
     /// Read an u8 from stream
     ///
@@ -164,9 +642,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_u8(&mut self) -> Result<u8, Error> {
         let mut buffer: [u8; 1] = [0u8; 1];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<u8>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
 
         Ok(buffer[0])
     }
@@ -197,9 +673,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_i8(&mut self) -> Result<i8, Error> {
         let mut buffer: [u8; 1] = [0u8; 1];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<i8>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
 
         Ok(buffer[0] as i8)
     }
@@ -233,9 +707,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_u16(&mut self, endianness: Endianness) -> Result<u16, Error> {
         let mut buffer: [u8; 2] = [0u8; 2];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<u16>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
         swap_inplace(&mut buffer, endianness);
 
         Ok(u16::from_ne_bytes(buffer))
@@ -361,9 +833,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_i16(&mut self, endianness: Endianness) -> Result<i16, Error> {
         let mut buffer: [u8; 2] = [0u8; 2];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<i16>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
         swap_inplace(&mut buffer, endianness);
 
         Ok(i16::from_ne_bytes(buffer))
@@ -486,9 +956,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_u32(&mut self, endianness: Endianness) -> Result<u32, Error> {
         let mut buffer: [u8; 4] = [0u8; 4];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<u32>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
         swap_inplace(&mut buffer, endianness);
 
         Ok(u32::from_ne_bytes(buffer))
@@ -601,9 +1069,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_i32(&mut self, endianness: Endianness) -> Result<i32, Error> {
         let mut buffer: [u8; 4] = [0u8; 4];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<i32>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
         swap_inplace(&mut buffer, endianness);
 
         Ok(i32::from_ne_bytes(buffer))
@@ -717,9 +1183,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_u64(&mut self, endianness: Endianness) -> Result<u64, Error> {
         let mut buffer: [u8; 8] = [0u8; 8];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<u64>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
         swap_inplace(&mut buffer, endianness);
 
         Ok(u64::from_ne_bytes(buffer))
@@ -836,9 +1300,7 @@ impl<StreamT: Read > BinaryReader for StreamT {
     fn read_i64(&mut self, endianness: Endianness) -> Result<i64, Error> {
         let mut buffer: [u8; 8] = [0u8; 8];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<i64>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.read_exact(&mut buffer)?;
         swap_inplace(&mut buffer, endianness);
 
         Ok(i64::from_ne_bytes(buffer))
@@ -940,83 +1402,77 @@ impl<StreamT: Read > BinaryReader for StreamT {
         Ok(())
     }
 
-    // ------------------------------------------------------------------------------- f32
-    /// Read an f32 from the stream
+    /// Read a u24 (stored in 3 bytes) from the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
-    /// # Parameters
-    ///    `endianness` - Endianness of the stream to read from
-    ///
     /// # Returns
-    ///     f32 value read from the stream
-    fn read_f32(&mut self, endianness: Endianness) -> Result<f32, Error> {
-        let mut buffer: [u8; 4] = [0u8; 4];
+    ///     u32 value read from the stream
+    fn read_u24(&mut self, endianness: Endianness) -> Result<u32, Error> {
+        let mut buffer: [u8; 3] = [0u8; 3];
 
-        if self.read(&mut buffer)? != std::mem::size_of::<f32>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
-        swap_inplace(&mut buffer, endianness);
+        self.read_exact(&mut buffer)?;
 
-        Ok(f32::from_ne_bytes(buffer))
+        Ok(unsigned_from_bytes(&buffer, endianness) as u32)
     }
 
-    /// Read a little endian f32
+    /// Read a little endian u24
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     f32 value read from the stream
-    fn read_le_f32(&mut self) -> Result<f32, Error> {
-        self.read_f32(Endianness::Little)
+    ///     u32 value read from the stream
+    fn read_le_u24(&mut self) -> Result<u32, Error> {
+        self.read_u24(Endianness::Little)
     }
 
-    /// Read a big endian f32
+    /// Read a big endian u24
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     f32 value read from the stream
-    fn read_be_f32(&mut self) -> Result<f32, Error> {
-        self.read_f32(Endianness::Big)
+    ///     u32 value read from the stream
+    fn read_be_u24(&mut self) -> Result<u32, Error> {
+        self.read_u24(Endianness::Big)
     }
 
-    /// Read a native endian f32
+    /// Read a native endian u24
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     f32 value read from the stream
-    fn read_ne_f32(&mut self) -> Result<f32, Error> {
-        self.read_f32(*SYS_ENDIANNESS)
+    ///     u32 value read from the stream
+    fn read_ne_u24(&mut self) -> Result<u32, Error> {
+        self.read_u24(*SYS_ENDIANNESS)
     }
 
-    /// Read a f32 into a variable
-    ///
-    /// # Errors
-    ///     io::Error during read
-    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    /// Read a u24 into a variable
     ///
     /// # Parameters
+    ///     `target` - The variable to store the value in
     ///     `endianness` - Endianness of the stream to read from
     ///
-    /// # Returns
-    ///     f32 value read from the stream
-    fn read_into_f32(&mut self, target: &mut f32, endianness: Endianness) -> Result<(), Error> {
-        *target = self.read_f32(endianness)?;
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_u24(&mut self, target: &mut u32, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_u24(endianness)?;
 
         Ok(())
     }
 
-    /// Read a little endian f32 into a variable
+    /// Read a little endian u24 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1024,13 +1480,13 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_le_f32(&mut self, target: &mut f32) -> Result<(), Error> {
-        *target = self.read_f32(Endianness::Little)?;
+    fn read_into_le_u24(&mut self, target: &mut u32) -> Result<(), Error> {
+        *target = self.read_u24(Endianness::Little)?;
 
         Ok(())
     }
 
-    /// Read a big endian f32 into a variable
+    /// Read a big endian u24 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1038,13 +1494,13 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_be_f32(&mut self, target: &mut f32) -> Result<(), Error> {
-        *target = self.read_f32(Endianness::Big)?;
+    fn read_into_be_u24(&mut self, target: &mut u32) -> Result<(), Error> {
+        *target = self.read_u24(Endianness::Big)?;
 
         Ok(())
     }
 
-    /// Read a native endian f32 into a variable
+    /// Read a native endian u24 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1052,90 +1508,194 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_ne_f32(&mut self, target: &mut f32) -> Result<(), Error> {
-        *target = self.read_f32(Endianness::Big)?;
+    fn read_into_ne_u24(&mut self, target: &mut u32) -> Result<(), Error> {
+        *target = self.read_u24(*SYS_ENDIANNESS)?;
 
         Ok(())
     }
 
-    // ------------------------------------------------------------------------------- f64
-    /// Read an f64 from the stream
+    /// Read an i24 (stored in 3 bytes) from the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
-    /// # Parameters
-    ///    `endianness` - Endianness of the stream to read from
-    ///
     /// # Returns
-    ///     f64 value read from the stream
-    fn read_f64(&mut self, endianness: Endianness) -> Result<f64, Error> {
-        let mut buffer: [u8; 8] = [0u8; 8];
-
-        if self.read(&mut buffer)? != std::mem::size_of::<f64>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
-        swap_inplace(&mut buffer, endianness);
+    ///     i32 value read from the stream
+    fn read_i24(&mut self, endianness: Endianness) -> Result<i32, Error> {
+        let value = self.read_u24(endianness)?;
 
-        Ok(f64::from_ne_bytes(buffer))
+        // Shift the sign bit to the top of the i32 and back to sign extend
+        Ok(((value << 8) as i32) >> 8)
     }
 
-    /// Read a little endian f64
+    /// Read a little endian i24
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     f64 value read from the stream
-    fn read_le_f64(&mut self) -> Result<f64, Error> {
-        self.read_f64(Endianness::Little)
+    ///     i32 value read from the stream
+    fn read_le_i24(&mut self) -> Result<i32, Error> {
+        self.read_i24(Endianness::Little)
     }
 
-    /// Read a big endian f64
+    /// Read a big endian i24
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     f64 value read from the stream
-    fn read_be_f64(&mut self) -> Result<f64, Error> {
-        self.read_f64(Endianness::Big)
+    ///     i32 value read from the stream
+    fn read_be_i24(&mut self) -> Result<i32, Error> {
+        self.read_i24(Endianness::Big)
     }
 
-    /// Read a native endian f64
+    /// Read a native endian i24
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     f64 value read from the stream
-    fn read_ne_f64(&mut self) -> Result<f64, Error> {
-        self.read_f64(Endianness::Big)
+    ///     i32 value read from the stream
+    fn read_ne_i24(&mut self) -> Result<i32, Error> {
+        self.read_i24(*SYS_ENDIANNESS)
     }
 
-    /// Read a f64 into a variable
+    /// Read an i24 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///     `endianness` - Endianness of the stream to read from
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_i24(&mut self, target: &mut i32, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_i24(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endian i24 into a variable
     ///
     /// # Parameters
-    ///     `endianness` - Endianness of the stream to read from
     ///     `target` - The variable to store the value in
     ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_i24(&mut self, target: &mut i32) -> Result<(), Error> {
+        *target = self.read_i24(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian i24 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_i24(&mut self, target: &mut i32) -> Result<(), Error> {
+        *target = self.read_i24(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endian i24 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_i24(&mut self, target: &mut i32) -> Result<(), Error> {
+        *target = self.read_i24(*SYS_ENDIANNESS)?;
+
+        Ok(())
+    }
+
+    /// Read a u48 (stored in 6 bytes) from the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
     /// # Returns
-    ///     f64 value read from the stream
-    fn read_into_f64(&mut self, target: &mut f64, endianness: Endianness) -> Result<(), Error> {
-        *target = self.read_f64(endianness)?;
+    ///     u64 value read from the stream
+    fn read_u48(&mut self, endianness: Endianness) -> Result<u64, Error> {
+        let mut buffer: [u8; 6] = [0u8; 6];
+
+        self.read_exact(&mut buffer)?;
+
+        Ok(unsigned_from_bytes(&buffer, endianness))
+    }
+
+    /// Read a little endian u48
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_le_u48(&mut self) -> Result<u64, Error> {
+        self.read_u48(Endianness::Little)
+    }
+
+    /// Read a big endian u48
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_be_u48(&mut self) -> Result<u64, Error> {
+        self.read_u48(Endianness::Big)
+    }
+
+    /// Read a native endian u48
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_ne_u48(&mut self) -> Result<u64, Error> {
+        self.read_u48(*SYS_ENDIANNESS)
+    }
+
+    /// Read a u48 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_u48(&mut self, target: &mut u64, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_u48(endianness)?;
 
         Ok(())
     }
 
-    /// Read a little endian f64 into a variable
+    /// Read a little endian u48 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1143,13 +1703,13 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_le_f64(&mut self, target: &mut f64) -> Result<(), Error> {
-        *target = self.read_f64(Endianness::Little)?;
+    fn read_into_le_u48(&mut self, target: &mut u64) -> Result<(), Error> {
+        *target = self.read_u48(Endianness::Little)?;
 
         Ok(())
     }
 
-    /// Read a big endian f64 into a variable
+    /// Read a big endian u48 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1157,13 +1717,13 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_be_f64(&mut self, target: &mut f64) -> Result<(), Error> {
-        *target = self.read_f64(Endianness::Big)?;
+    fn read_into_be_u48(&mut self, target: &mut u64) -> Result<(), Error> {
+        *target = self.read_u48(Endianness::Big)?;
 
         Ok(())
     }
 
-    /// Read a native endian f64 into a variable
+    /// Read a native endian u48 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1171,83 +1731,110 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_ne_f64(&mut self, target: &mut f64) -> Result<(), Error> {
-        *target = self.read_f64(Endianness::Big)?;
+    fn read_into_ne_u48(&mut self, target: &mut u64) -> Result<(), Error> {
+        *target = self.read_u48(*SYS_ENDIANNESS)?;
 
         Ok(())
     }
 
-    // ------------------------------------------------------------------------------- Strings
-    /// Read an fixed length string from the stream
+    /// Read an i48 (stored in 6 bytes) from the stream
     ///
     /// # Parameters
-    ///     `size` - The size of the string to read from stream
+    ///    `endianness` - Endianness of the stream to read from
     ///
     /// # Errors
-    ///     io::Error                - during read
-    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
-    ///     io::Error::InvalidData   - When non-utf-8 data is read from stream
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     String value read from the stream
-    fn read_fixed_size_string(&mut self, size: usize) -> Result<String, Error> {
-        let mut buffer: Vec<u8> = vec![0u8; size];
+    ///     i64 value read from the stream
+    fn read_i48(&mut self, endianness: Endianness) -> Result<i64, Error> {
+        let value = self.read_u48(endianness)?;
 
-        if self.read(&mut buffer)? != size {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        // Shift the sign bit to the top of the i64 and back to sign extend
+        Ok(((value << 16) as i64) >> 16)
+    }
 
-        let string: String = {
-            match String::from_utf8(buffer) {
-                Ok(value) => value,
-                Err(_) => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        "Unable to convert bytes to string.",
-                    ))
-                }
-            }
-        };
+    /// Read a little endian i48
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i64 value read from the stream
+    fn read_le_i48(&mut self) -> Result<i64, Error> {
+        self.read_i48(Endianness::Little)
+    }
 
-        Ok(string)
+    /// Read a big endian i48
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i64 value read from the stream
+    fn read_be_i48(&mut self) -> Result<i64, Error> {
+        self.read_i48(Endianness::Big)
     }
 
-    /// Read a fixed size string into a variable
+    /// Read a native endian i48
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i64 value read from the stream
+    fn read_ne_i48(&mut self) -> Result<i64, Error> {
+        self.read_i48(*SYS_ENDIANNESS)
+    }
+
+    /// Read an i48 into a variable
     ///
     /// # Parameters
-    ///     `size` - The size of the string to read from stream
     ///     `target` - The variable to store the value in
+    ///     `endianness` - Endianness of the stream to read from
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    ///
-    /// # Returns
-    ///     u64 value read from the stream
-    fn read_into_fixed_size_string(
-        &mut self,
-        size: usize,
-        target: &mut String,
-    ) -> Result<(), Error> {
-        *target = self.read_fixed_size_string(size)?;
+    fn read_into_i48(&mut self, target: &mut i64, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_i48(endianness)?;
 
         Ok(())
     }
 
-    /// Read a a null terminated string from the stream
+    /// Read a little endian i48 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_i48(&mut self, target: &mut i64) -> Result<(), Error> {
+        *target = self.read_i48(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian i48 into a variable
     ///
-    /// # Returns
-    ///     u64 value read from the stream
-    fn read_null_terminated_string(&mut self) -> Result<String, Error> {
-        let buffer = self.read_to_delim(0)?;
-        Ok(String::from_utf8(buffer.to_vec()).or(Err(Error::from(ErrorKind::InvalidData)))?)
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_i48(&mut self, target: &mut i64) -> Result<(), Error> {
+        *target = self.read_i48(Endianness::Big)?;
+
+        Ok(())
     }
 
-    /// Read a null terminated string into a variable
+    /// Read a native endian i48 into a variable
     ///
     /// # Parameters
     ///     `target` - The variable to store the value in
@@ -1255,58 +1842,2092 @@ impl<StreamT: Read > BinaryReader for StreamT {
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_null_terminated_string(&mut self, target: &mut String) -> Result<(), Error> {
-        *target = self.read_null_terminated_string()?;
+    fn read_into_ne_i48(&mut self, target: &mut i64) -> Result<(), Error> {
+        *target = self.read_i48(*SYS_ENDIANNESS)?;
+
         Ok(())
     }
 
-    /// Read a size prefixed string from the stream
+    /// Read a u128 from the stream
     ///
     /// # Parameters
-    ///     `endianness` - Endianness of the stream to read from
+    ///    `endianness` - Endianness of the stream to read from
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
     ///
     /// # Returns
-    ///     u64 value read from the stream
-    fn read_size_prefixed_string<PrefixT: PrimInt>(
-        &mut self,
-        endianness: Endianness,
-    ) -> Result<String, Error> {
-        let mut buffer: [u8; 8] = [0u8; std::mem::size_of::<usize>()];
+    ///     u128 value read from the stream
+    fn read_u128(&mut self, endianness: Endianness) -> Result<u128, Error> {
+        let mut buffer: [u8; 16] = [0u8; 16];
 
-        let size = match endianness {
-            Endianness::Little => {
-                self.read(&mut buffer[..std::mem::size_of::<PrefixT>()])?;
-                usize::from_le_bytes(buffer)
-            }
-            Endianness::Big => {
-                self.read(&mut buffer[8 - std::mem::size_of::<PrefixT>()..])?;
-                usize::from_be_bytes(buffer)
-            }
-        };
+        self.read_exact(&mut buffer)?;
+        swap_inplace(&mut buffer, endianness);
 
-        Ok(self.read_fixed_size_string(size)?)
+        Ok(u128::from_ne_bytes(buffer))
     }
 
-    /// Read a size prefixed string into a variable
+    /// Read a little endian u128
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u128 value read from the stream
+    fn read_le_u128(&mut self) -> Result<u128, Error> {
+        self.read_u128(Endianness::Little)
+    }
+
+    /// Read a big endian u128
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u128 value read from the stream
+    fn read_be_u128(&mut self) -> Result<u128, Error> {
+        self.read_u128(Endianness::Big)
+    }
+
+    /// Read a native endian u128
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u128 value read from the stream
+    fn read_ne_u128(&mut self) -> Result<u128, Error> {
+        self.read_u128(*SYS_ENDIANNESS)
+    }
+
+    /// Read a u128 into a variable
     ///
     /// # Parameters
+    ///     `target` - The variable to store the value in
     ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_u128(&mut self, target: &mut u128, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_u128(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endian u128 into a variable
+    ///
+    /// # Parameters
     ///     `target` - The variable to store the value in
     ///
     /// # Errors
     ///     io::Error during read
     ///     io::Error::UnexpectedEof when there isn't enough data in the stream
-    fn read_into_size_prefixed_string<PrefixT: PrimInt>(
-        &mut self,
-        target: &mut String,
-        endianness: Endianness,
-    ) -> Result<(), Error> {
-        *target = self.read_size_prefixed_string::<PrefixT>(endianness)?;
+    fn read_into_le_u128(&mut self, target: &mut u128) -> Result<(), Error> {
+        *target = self.read_u128(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian u128 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_u128(&mut self, target: &mut u128) -> Result<(), Error> {
+        *target = self.read_u128(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endian u128 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_u128(&mut self, target: &mut u128) -> Result<(), Error> {
+        *target = self.read_u128(*SYS_ENDIANNESS)?;
 
         Ok(())
     }
+
+    /// Read an i128 from the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i128 value read from the stream
+    fn read_i128(&mut self, endianness: Endianness) -> Result<i128, Error> {
+        let mut buffer: [u8; 16] = [0u8; 16];
+
+        self.read_exact(&mut buffer)?;
+        swap_inplace(&mut buffer, endianness);
+
+        Ok(i128::from_ne_bytes(buffer))
+    }
+
+    /// Read a little endian i128
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i128 value read from the stream
+    fn read_le_i128(&mut self) -> Result<i128, Error> {
+        self.read_i128(Endianness::Little)
+    }
+
+    /// Read a big endian i128
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i128 value read from the stream
+    fn read_be_i128(&mut self) -> Result<i128, Error> {
+        self.read_i128(Endianness::Big)
+    }
+
+    /// Read a native endian i128
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     i128 value read from the stream
+    fn read_ne_i128(&mut self) -> Result<i128, Error> {
+        self.read_i128(*SYS_ENDIANNESS)
+    }
+
+    /// Read an i128 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_i128(&mut self, target: &mut i128, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_i128(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endian i128 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_i128(&mut self, target: &mut i128) -> Result<(), Error> {
+        *target = self.read_i128(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian i128 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_i128(&mut self, target: &mut i128) -> Result<(), Error> {
+        *target = self.read_i128(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endian i128 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_i128(&mut self, target: &mut i128) -> Result<(), Error> {
+        *target = self.read_i128(*SYS_ENDIANNESS)?;
+
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------------------- f32
+    /// Read an f32 from the stream
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
+    ///
+    /// # Returns
+    ///     f32 value read from the stream
+    fn read_f32(&mut self, endianness: Endianness) -> Result<f32, Error> {
+        let mut buffer: [u8; 4] = [0u8; 4];
+
+        self.read_exact(&mut buffer)?;
+        swap_inplace(&mut buffer, endianness);
+
+        Ok(f32::from_ne_bytes(buffer))
+    }
+
+    /// Read a little endian f32
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f32 value read from the stream
+    fn read_le_f32(&mut self) -> Result<f32, Error> {
+        self.read_f32(Endianness::Little)
+    }
+
+    /// Read a big endian f32
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f32 value read from the stream
+    fn read_be_f32(&mut self) -> Result<f32, Error> {
+        self.read_f32(Endianness::Big)
+    }
+
+    /// Read a native endian f32
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f32 value read from the stream
+    fn read_ne_f32(&mut self) -> Result<f32, Error> {
+        self.read_f32(*SYS_ENDIANNESS)
+    }
+
+    /// Read a f32 into a variable
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Returns
+    ///     f32 value read from the stream
+    fn read_into_f32(&mut self, target: &mut f32, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_f32(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endian f32 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_f32(&mut self, target: &mut f32) -> Result<(), Error> {
+        *target = self.read_f32(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian f32 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_f32(&mut self, target: &mut f32) -> Result<(), Error> {
+        *target = self.read_f32(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endian f32 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_f32(&mut self, target: &mut f32) -> Result<(), Error> {
+        *target = self.read_f32(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------------------- f64
+    /// Read an f64 from the stream
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_f64(&mut self, endianness: Endianness) -> Result<f64, Error> {
+        let mut buffer: [u8; 8] = [0u8; 8];
+
+        self.read_exact(&mut buffer)?;
+        swap_inplace(&mut buffer, endianness);
+
+        Ok(f64::from_ne_bytes(buffer))
+    }
+
+    /// Read a little endian f64
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_le_f64(&mut self) -> Result<f64, Error> {
+        self.read_f64(Endianness::Little)
+    }
+
+    /// Read a big endian f64
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_be_f64(&mut self) -> Result<f64, Error> {
+        self.read_f64(Endianness::Big)
+    }
+
+    /// Read a native endian f64
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_ne_f64(&mut self) -> Result<f64, Error> {
+        self.read_f64(Endianness::Big)
+    }
+
+    /// Read a f64 into a variable
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_into_f64(&mut self, target: &mut f64, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_f64(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endian f64 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_f64(&mut self, target: &mut f64) -> Result<(), Error> {
+        *target = self.read_f64(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian f64 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_f64(&mut self, target: &mut f64) -> Result<(), Error> {
+        *target = self.read_f64(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endian f64 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_f64(&mut self, target: &mut f64) -> Result<(), Error> {
+        *target = self.read_f64(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a f16 from the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f16 value read from the stream
+    fn read_f16(&mut self, endianness: Endianness) -> Result<f16, Error> {
+        let mut buffer: [u8; 2] = [0u8; 2];
+
+        self.read_exact(&mut buffer)?;
+        swap_inplace(&mut buffer, endianness);
+
+        Ok(f16::from_ne_bytes(buffer))
+    }
+
+    /// Read a little endian f16
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f16 value read from the stream
+    fn read_le_f16(&mut self) -> Result<f16, Error> {
+        self.read_f16(Endianness::Little)
+    }
+
+    /// Read a big endian f16
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f16 value read from the stream
+    fn read_be_f16(&mut self) -> Result<f16, Error> {
+        self.read_f16(Endianness::Big)
+    }
+
+    /// Read a native endian f16
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f16 value read from the stream
+    fn read_ne_f16(&mut self) -> Result<f16, Error> {
+        self.read_f16(*SYS_ENDIANNESS)
+    }
+
+    /// Read a f16 into a variable
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f16 value read from the stream
+    fn read_into_f16(&mut self, target: &mut f16, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_f16(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endia f16 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_f16(&mut self, target: &mut f16) -> Result<(), Error> {
+        *target = self.read_f16(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endia f16 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_f16(&mut self, target: &mut f16) -> Result<(), Error> {
+        *target = self.read_f16(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endia f16 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_f16(&mut self, target: &mut f16) -> Result<(), Error> {
+        *target = self.read_f16(*SYS_ENDIANNESS)?;
+
+        Ok(())
+    }
+
+    /// Read a bf16 from the stream
+    ///
+    /// # Parameters
+    ///    `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     bf16 value read from the stream
+    fn read_bf16(&mut self, endianness: Endianness) -> Result<bf16, Error> {
+        let mut buffer: [u8; 2] = [0u8; 2];
+
+        self.read_exact(&mut buffer)?;
+        swap_inplace(&mut buffer, endianness);
+
+        Ok(bf16::from_ne_bytes(buffer))
+    }
+
+    /// Read a little endian bf16
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     bf16 value read from the stream
+    fn read_le_bf16(&mut self) -> Result<bf16, Error> {
+        self.read_bf16(Endianness::Little)
+    }
+
+    /// Read a big endian bf16
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     bf16 value read from the stream
+    fn read_be_bf16(&mut self) -> Result<bf16, Error> {
+        self.read_bf16(Endianness::Big)
+    }
+
+    /// Read a native endian bf16
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     bf16 value read from the stream
+    fn read_ne_bf16(&mut self) -> Result<bf16, Error> {
+        self.read_bf16(*SYS_ENDIANNESS)
+    }
+
+    /// Read a bf16 into a variable
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     bf16 value read from the stream
+    fn read_into_bf16(&mut self, target: &mut bf16, endianness: Endianness) -> Result<(), Error> {
+        *target = self.read_bf16(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endia bf16 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_bf16(&mut self, target: &mut bf16) -> Result<(), Error> {
+        *target = self.read_bf16(Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endia bf16 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_bf16(&mut self, target: &mut bf16) -> Result<(), Error> {
+        *target = self.read_bf16(Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endia bf16 into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_bf16(&mut self, target: &mut bf16) -> Result<(), Error> {
+        *target = self.read_bf16(*SYS_ENDIANNESS)?;
+
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------------------- Fixed point
+    /// Read a fixed point number stored as an `IntT` with `fractional_bits` fractional bits
+    /// (e.g. `read_fixed_point::<i32>(16, ..)` for 16.16, `read_fixed_point::<i16>(15, ..)` for 1.15)
+    ///
+    /// # Parameters
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///     `endianness`      - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_fixed_point<IntT>(
+        &mut self,
+        fractional_bits: u32,
+        endianness: Endianness,
+    ) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        let mut buffer = IntT::Bytes::default();
+        self.read_exact(buffer.as_mut())?;
+        swap_inplace(buffer.as_mut(), endianness);
+
+        let value = IntT::from_ne_bytes(&buffer)
+            .to_f64()
+            .ok_or(Error::from(ErrorKind::InvalidData))?;
+
+        Ok(value / 2f64.powi(fractional_bits as i32))
+    }
+
+    /// Read a little endian fixed point number
+    ///
+    /// # Parameters
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_le_fixed_point<IntT>(&mut self, fractional_bits: u32) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        self.read_fixed_point::<IntT>(fractional_bits, Endianness::Little)
+    }
+
+    /// Read a big endian fixed point number
+    ///
+    /// # Parameters
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_be_fixed_point<IntT>(&mut self, fractional_bits: u32) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        self.read_fixed_point::<IntT>(fractional_bits, Endianness::Big)
+    }
+
+    /// Read a native endian fixed point number
+    ///
+    /// # Parameters
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     f64 value read from the stream
+    fn read_ne_fixed_point<IntT>(&mut self, fractional_bits: u32) -> Result<f64, Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        self.read_fixed_point::<IntT>(fractional_bits, *SYS_ENDIANNESS)
+    }
+
+    /// Read a fixed point number into a variable
+    ///
+    /// # Parameters
+    ///     `target`          - The variable to store the value in
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///     `endianness`      - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+        endianness: Endianness,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        *target = self.read_fixed_point::<IntT>(fractional_bits, endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a little endian fixed point number into a variable
+    ///
+    /// # Parameters
+    ///     `target`          - The variable to store the value in
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_le_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        *target = self.read_fixed_point::<IntT>(fractional_bits, Endianness::Little)?;
+
+        Ok(())
+    }
+
+    /// Read a big endian fixed point number into a variable
+    ///
+    /// # Parameters
+    ///     `target`          - The variable to store the value in
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_be_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        *target = self.read_fixed_point::<IntT>(fractional_bits, Endianness::Big)?;
+
+        Ok(())
+    }
+
+    /// Read a native endian fixed point number into a variable
+    ///
+    /// # Parameters
+    ///     `target`          - The variable to store the value in
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_ne_fixed_point<IntT>(
+        &mut self,
+        target: &mut f64,
+        fractional_bits: u32,
+    ) -> Result<(), Error>
+    where
+        IntT: PrimInt + FromBytes,
+        IntT::Bytes: Sized + Default,
+    {
+        *target = self.read_fixed_point::<IntT>(fractional_bits, *SYS_ENDIANNESS)?;
+
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------------------- Bulk
+    /// Fill `target` with u8 values using a single read
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_u8_slice_into(&mut self, target: &mut [u8]) -> Result<(), Error> {
+        self.read_exact(target)
+    }
+
+    /// Read `count` u8 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_u8_vec(&mut self, count: usize) -> Result<Vec<u8>, Error> {
+        read_plain_vec(self, count, *SYS_ENDIANNESS)
+    }
+
+    /// Fill `target` with i8 values using a single read
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_i8_slice_into(&mut self, target: &mut [i8]) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))
+    }
+
+    /// Read `count` i8 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_i8_vec(&mut self, count: usize) -> Result<Vec<i8>, Error> {
+        read_plain_vec(self, count, *SYS_ENDIANNESS)
+    }
+
+    /// Fill `target` with u16 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_u16_slice_into(&mut self, target: &mut [u16], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` u16 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_u16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u16>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with i16 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_i16_slice_into(&mut self, target: &mut [i16], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` i16 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_i16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i16>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with u32 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_u32_slice_into(&mut self, target: &mut [u32], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` u32 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_u32_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u32>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with i32 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_i32_slice_into(&mut self, target: &mut [i32], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` i32 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_i32_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i32>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with u64 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_u64_slice_into(&mut self, target: &mut [u64], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` u64 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_u64_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u64>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with i64 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_i64_slice_into(&mut self, target: &mut [i64], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` i64 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_i64_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i64>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with u128 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_u128_slice_into(&mut self, target: &mut [u128], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` u128 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_u128_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<u128>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with i128 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_i128_slice_into(&mut self, target: &mut [i128], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` i128 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_i128_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<i128>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with f16 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_f16_slice_into(&mut self, target: &mut [f16], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` f16 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_f16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<f16>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with bf16 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_bf16_slice_into(&mut self, target: &mut [bf16], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` bf16 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_bf16_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<bf16>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with f32 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_f32_slice_into(&mut self, target: &mut [f32], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` f32 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_f32_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<f32>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    /// Fill `target` with f64 values using a single read, swapping them in place
+    /// only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `target` - The values to read into
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_f64_slice_into(&mut self, target: &mut [f64], endianness: Endianness) -> Result<(), Error> {
+        self.read_exact(as_bytes_mut(target))?;
+        swap_slice_inplace(target, endianness);
+
+        Ok(())
+    }
+
+    /// Read `count` f64 values with bulk reads of up to 64 KiB each
+    ///
+    /// # Parameters
+    ///     `count` - Number of values to read
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     Vec of the values read from the stream
+    fn read_f64_vec(&mut self, count: usize, endianness: Endianness) -> Result<Vec<f64>, Error> {
+        read_plain_vec(self, count, endianness)
+    }
+
+    // ------------------------------------------------------------------------------- Strings
+    /// Read an fixed length string from the stream
+    ///
+    /// # Parameters
+    ///     `size` - The size of the string to read from stream
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When non-utf-8 data is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_fixed_size_string(&mut self, size: usize) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, Some(size));
+        let buffer: Vec<u8> = read_plain_vec(self, size, *SYS_ENDIANNESS)?;
+
+        let string: String = {
+            match String::from_utf8(buffer) {
+                Ok(value) => value,
+                Err(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Unable to convert bytes to string.",
+                    ))
+                }
+            }
+        };
+
+        Ok(string)
+    }
+
+    /// Read a fixed size string into a variable
+    ///
+    /// # Parameters
+    ///     `size` - The size of the string to read from stream
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_into_fixed_size_string(
+        &mut self,
+        size: usize,
+        target: &mut String,
+    ) -> Result<(), Error> {
+        *target = self.read_fixed_size_string(size)?;
+
+        Ok(())
+    }
+
+    /// Read a a null terminated string from the stream
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_null_terminated_string(&mut self) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, None);
+        let buffer = self.read_to_delim(0)?;
+        Ok(String::from_utf8(buffer.to_vec()).or(Err(Error::from(ErrorKind::InvalidData)))?)
+    }
+
+    /// Read a null terminated string into a variable
+    ///
+    /// # Parameters
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_null_terminated_string(&mut self, target: &mut String) -> Result<(), Error> {
+        *target = self.read_null_terminated_string()?;
+        Ok(())
+    }
+
+    /// Read a size prefixed string from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///     io::Error::InvalidData when a signed prefix is negative
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_size_prefixed_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+    ) -> Result<String, Error> {
+        let size = read_size_prefix::<Self, PrefixT>(self, endianness)?;
+
+        Ok(self.read_fixed_size_string(size)?)
+    }
+
+    /// Read a size prefixed string into a variable
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the stream to read from
+    ///     `target` - The variable to store the value in
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    fn read_into_size_prefixed_string<PrefixT: PrimInt>(
+        &mut self,
+        target: &mut String,
+        endianness: Endianness,
+    ) -> Result<(), Error> {
+        *target = self.read_size_prefixed_string::<PrefixT>(endianness)?;
+
+        Ok(())
+    }
+
+    /// Read a size prefixed blob of bytes from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the size prefix
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof when there isn't enough data in the stream
+    ///     io::Error::InvalidData when a signed prefix is negative
+    ///
+    /// # Returns
+    ///     Arc<[u8]> - The bytes following the prefix
+    fn read_size_prefixed_bytes<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+    ) -> Result<Arc<[u8]>, Error> {
+        let size = read_size_prefix::<Self, PrefixT>(self, endianness)?;
+
+        self.read_raw(size)
+    }
+
+    // ------------------------------------------------------------------------------- Encoded strings
+    /// Read a fixed length string in a specific encoding from the stream
+    ///
+    /// # Parameters
+    ///     `size`     - The size of the string in bytes
+    ///     `encoding` - The character encoding of the string
+    ///     `lossy`    - Replace invalid sequences with U+FFFD instead of failing
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the data is not valid in `encoding` and `lossy` is not set
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_fixed_size_encoded_string(
+        &mut self,
+        size: usize,
+        encoding: Encoding,
+        lossy: bool,
+    ) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, Some(size));
+        let buffer = self.read_raw(size)?;
+
+        encoding.decode(buffer.to_vec(), lossy)
+    }
+
+    /// Read a null terminated string in a specific encoding from the stream
+    ///
+    /// # Parameters
+    ///     `encoding` - The character encoding of the string
+    ///     `lossy`    - Replace invalid sequences with U+FFFD instead of failing
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when the stream ends before the terminator
+    ///     io::Error::InvalidData   - When the data is not valid in `encoding` and `lossy` is not set
+    ///
+    /// # Returns
+    ///     String value read from the stream, without the terminator
+    fn read_null_terminated_encoded_string(
+        &mut self,
+        encoding: Encoding,
+        lossy: bool,
+    ) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, None);
+        let buffer = self.read_to_delim(0)?;
+
+        encoding.decode(buffer.to_vec(), lossy)
+    }
+
+    /// Read a size prefixed string in a specific encoding from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the size prefix
+    ///     `encoding`   - The character encoding of the string
+    ///     `lossy`      - Replace invalid sequences with U+FFFD instead of failing
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the data is not valid in `encoding` and `lossy` is not set
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_size_prefixed_encoded_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+        encoding: Encoding,
+        lossy: bool,
+    ) -> Result<String, Error> {
+        let size = read_size_prefix::<Self, PrefixT>(self, endianness)?;
+
+        self.read_fixed_size_encoded_string(size, encoding, lossy)
+    }
+
+    // ------------------------------------------------------------------------------- Varints
+    /// Read an unsigned LEB128 variable length integer from the stream
+    ///
+    /// # Parameters
+    ///     `max_length` - The most bytes the encoded value may take up
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the value is longer than `max_length` or overflows a u64
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_uleb128(&mut self, max_length: usize) -> Result<u64, Error> {
+        let mut result: u64 = 0;
+        let mut shift: u32 = 0;
+
+        for _ in 0..max_length {
+            let byte = self.read_u8()?;
+            let group = (byte & 0x7F) as u64;
+
+            // The 10th group only has room for bit 63, past it groups must be empty
+            if shift < u64::BITS && group >> (u64::BITS - shift).min(7) == 0 {
+                result |= group << shift;
+            } else if group != 0 {
+                return Err(varint_overflow());
+            }
+
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift = (shift + 7).min(u64::BITS);
+        }
+
+        Err(varint_too_long())
+    }
+
+    /// Read a signed LEB128 variable length integer from the stream
+    ///
+    /// # Parameters
+    ///     `max_length` - The most bytes the encoded value may take up
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the value is longer than `max_length` or overflows an i64
+    ///
+    /// # Returns
+    ///     i64 value read from the stream
+    fn read_sleb128(&mut self, max_length: usize) -> Result<i64, Error> {
+        let mut result: i64 = 0;
+        let mut shift: u32 = 0;
+
+        for _ in 0..max_length {
+            let byte = self.read_u8()?;
+            let group = (byte & 0x7F) as i64;
+
+            if shift < u64::BITS - 1 {
+                result |= group << shift;
+            } else if shift == u64::BITS - 1 && (group == 0 || group == 0x7F) {
+                // The 10th group holds the sign bit, its other bits have to repeat it
+                result |= group << shift;
+            } else if shift == u64::BITS - 1 || group != (result >> 63) & 0x7F {
+                // Past 64 bits every group must only repeat the sign
+                return Err(varint_overflow());
+            }
+            shift = (shift + 7).min(u64::BITS);
+
+            if byte & 0x80 == 0 {
+                if shift < u64::BITS && byte & 0x40 != 0 {
+                    result |= -1i64 << shift;
+                }
+                return Ok(result);
+            }
+        }
+
+        Err(varint_too_long())
+    }
+
+    /// Read a zigzag encoded signed LEB128 variable length integer from the stream
+    /// (as used by Protocol Buffers `sint` fields)
+    ///
+    /// # Parameters
+    ///     `max_length` - The most bytes the encoded value may take up
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the value is longer than `max_length` or overflows an i64
+    ///
+    /// # Returns
+    ///     i64 value read from the stream
+    fn read_zigzag_varint(&mut self, max_length: usize) -> Result<i64, Error> {
+        let value = self.read_uleb128(max_length)?;
+
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    /// Read a big endian variable length quantity (as used by MIDI) from the stream
+    ///
+    /// # Parameters
+    ///     `max_length` - The most bytes the encoded value may take up
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the value is longer than `max_length` or overflows a u64
+    ///
+    /// # Returns
+    ///     u64 value read from the stream
+    fn read_vlq(&mut self, max_length: usize) -> Result<u64, Error> {
+        let mut result: u64 = 0;
+
+        for _ in 0..max_length {
+            let byte = self.read_u8()?;
+            if result >> (u64::BITS - 7) != 0 {
+                return Err(varint_overflow());
+            }
+            result = (result << 7) | (byte & 0x7F) as u64;
+
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+
+        Err(varint_too_long())
+    }
+
+    /// Read a 7 bit encoded int (as written by .NET's BinaryWriter) from the stream
+    ///
+    /// # Parameters
+    ///     `max_length` - The most bytes the encoded value may take up (5 covers every i32)
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the value is longer than `max_length` or overflows 32 bits
+    ///
+    /// # Returns
+    ///     i32 value read from the stream
+    fn read_7bit_encoded_int(&mut self, max_length: usize) -> Result<i32, Error> {
+        let value = self.read_uleb128(max_length)?;
+
+        Ok(u32::try_from(value).or(Err(varint_overflow()))? as i32)
+    }
+
+    // ------------------------------------------------------------------------------- UTF-16
+    /// Read a fixed length UTF-16 string from the stream
+    ///
+    /// # Parameters
+    ///     `length`     - The length of the string in code units (not bytes)
+    ///     `endianness` - Endianness of the code units
+    ///     `detect_bom` - When set, a leading byte order mark overrides `endianness` and is removed
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When invalid UTF-16 is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_fixed_size_utf16_string(
+        &mut self,
+        length: usize,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, Some(length.saturating_mul(2)));
+        let units: Vec<u16> = read_plain_vec(self, length, endianness)?;
+
+        decode_utf16(units, detect_bom)
+    }
+
+    /// Read a UTF-16 string terminated by a zero code unit from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the code units
+    ///     `detect_bom` - When set, a leading byte order mark overrides `endianness` and is removed
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when the stream ends before the terminator
+    ///     io::Error::InvalidData   - When invalid UTF-16 is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream, without the terminator
+    fn read_null_terminated_utf16_string(
+        &mut self,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, None);
+
+        let mut units: Vec<u16> = Vec::new();
+        loop {
+            let unit = self.read_u16(endianness)?;
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+        }
+
+        decode_utf16(units, detect_bom)
+    }
+
+    /// Read a size prefixed UTF-16 string from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the prefix and the code units
+    ///     `unit`       - Whether the prefix counts bytes or code units
+    ///     `detect_bom` - When set, a leading byte order mark overrides `endianness` and is removed
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the byte length is odd or invalid UTF-16 is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_size_prefixed_utf16_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+        unit: LengthUnit,
+        detect_bom: bool,
+    ) -> Result<String, Error> {
+        let length = read_length_prefix::<Self, PrefixT>(self, endianness, unit, 2)?;
+
+        self.read_fixed_size_utf16_string(length, endianness, detect_bom)
+    }
+
+    // ------------------------------------------------------------------------------- UTF-32
+    /// Read a fixed length UTF-32 string from the stream
+    ///
+    /// # Parameters
+    ///     `length`     - The length of the string in code units (not bytes)
+    ///     `endianness` - Endianness of the code units
+    ///     `detect_bom` - When set, a leading byte order mark overrides `endianness` and is removed
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When invalid UTF-32 is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_fixed_size_utf32_string(
+        &mut self,
+        length: usize,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, Some(length.saturating_mul(4)));
+        let units: Vec<u32> = read_plain_vec(self, length, endianness)?;
+
+        decode_utf32(units, detect_bom)
+    }
+
+    /// Read a UTF-32 string terminated by a zero code unit from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the code units
+    ///     `detect_bom` - When set, a leading byte order mark overrides `endianness` and is removed
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when the stream ends before the terminator
+    ///     io::Error::InvalidData   - When invalid UTF-32 is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream, without the terminator
+    fn read_null_terminated_utf32_string(
+        &mut self,
+        endianness: Endianness,
+        detect_bom: bool,
+    ) -> Result<String, Error> {
+        let _allocation = announce_allocation(AllocationKind::String, None);
+
+        let mut units: Vec<u32> = Vec::new();
+        loop {
+            let unit = self.read_u32(endianness)?;
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+        }
+
+        decode_utf32(units, detect_bom)
+    }
+
+    /// Read a size prefixed UTF-32 string from the stream
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness of the prefix and the code units
+    ///     `unit`       - Whether the prefix counts bytes or code units
+    ///     `detect_bom` - When set, a leading byte order mark overrides `endianness` and is removed
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidData   - When the byte length is not a multiple of 4 or invalid UTF-32 is read from stream
+    ///
+    /// # Returns
+    ///     String value read from the stream
+    fn read_size_prefixed_utf32_string<PrefixT: PrimInt>(
+        &mut self,
+        endianness: Endianness,
+        unit: LengthUnit,
+        detect_bom: bool,
+    ) -> Result<String, Error> {
+        let length = read_length_prefix::<Self, PrefixT>(self, endianness, unit, 4)?;
+
+        self.read_fixed_size_utf32_string(length, endianness, detect_bom)
+    }
+
+    // ------------------------------------------------------------------------------- Padding
+    /// Skip `length` bytes of padding, verifying that they repeat `fill`. Use
+    /// `BinaryUtils::ignore` (or `BinaryReader::discard` on streams that can't seek) to
+    /// skip padding without looking at it.
+    ///
+    /// # Parameters
+    ///     `length` - Number of padding bytes to skip
+    ///     `fill`   - The pattern the padding should repeat (EG `&[0]` or `b"PADDING"`)
+    ///
+    /// # Errors
+    ///     io::Error                - during read
+    ///     io::Error::UnexpectedEof - when there isn't enough data in the stream
+    ///     io::Error::InvalidInput  - When `fill` is empty
+    ///     io::Error::InvalidData   - When a byte does not match the pattern (a `binre::Error` with the expected and found byte)
+    fn skip_padding(&mut self, length: usize, fill: &[u8]) -> Result<(), Error> {
+        if fill.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Fill pattern is empty."));
+        }
+
+        let mut buffer = [0u8; 512];
+        let mut checked = 0;
+        while checked < length {
+            let chunk = &mut buffer[..(length - checked).min(512)];
+            self.read_exact(chunk)?;
+
+            let mismatch = chunk
+                .iter()
+                .enumerate()
+                .find(|(index, byte)| **byte != fill[(checked + index) % fill.len()]);
+            if let Some((index, byte)) = mismatch {
+                let index = checked + index;
+                return Err(error::Error::mismatch(
+                    format!("Padding byte {index} does not match the fill pattern."),
+                    fill[index % fill.len()],
+                    *byte,
+                )
+                .into());
+            }
+
+            checked += chunk.len();
+        }
+
+        Ok(())
+    }
+
+    /// Skip to the nearest `alignment` boundary, verifying that the skipped padding
+    /// repeats `fill`. Use `BinaryUtils::next_alignment` to align without looking at it.
+    ///
+    /// # Parameters
+    ///     `alignment` - The boundary to skip to, must be non-zero
+    ///     `fill`      - The pattern the padding should repeat
+    ///
+    /// # Errors
+    ///     io::Error::OutOfMemory  - Next alignment results in overflow of stream pos
+    ///     io::Error::InvalidInput - Alignment passed was zero, or `fill` is empty
+    ///     io::Error               - Errors of `skip_padding`
+    fn skip_to_alignment(&mut self, alignment: u64, fill: &[u8]) -> Result<(), Error>
+    where
+        Self: Seek + Sized,
+    {
+        if alignment == 0 {
+            return Err(Error::from(ErrorKind::InvalidInput));
+        }
+
+        let position = self.tell()?;
+        let length = position
+            .checked_next_multiple_of(alignment)
+            .and_then(|target| usize::try_from(target - position).ok())
+            .ok_or(Error::from(ErrorKind::OutOfMemory))?;
+
+        self.skip_padding(length, fill)
+    }
+}
+
+/// Largest amount `read_plain_vec` grows its buffer by at a time, in bytes
+const ALLOCATION_STEP: usize = 64 * 1024;
+
+/// Read `count` plain values. The buffer grows in steps as data arrives, so a corrupt
+/// count cannot allocate much more than the stream actually holds. The size is
+/// announced to any `LimitedReader` the data is read through.
+///
+/// # Errors
+///     io::Error during read
+///     io::Error::UnexpectedEof when there isn't enough data in the stream
+fn read_plain_vec<StreamT: Read + ?Sized, T: PlainData>(
+    stream: &mut StreamT,
+    count: usize,
+    endianness: Endianness,
+) -> Result<Vec<T>, Error> {
+    let _allocation =
+        announce_allocation(AllocationKind::Buffer, Some(count.saturating_mul(size_of::<T>())));
+
+    let step = (ALLOCATION_STEP / size_of::<T>()).max(1);
+
+    let mut values: Vec<T> = Vec::new();
+    while values.len() < count {
+        let start = values.len();
+        values.resize(start + step.min(count - start), T::default());
+        stream.read_exact(as_bytes_mut(&mut values[start..]))?;
+    }
+    swap_slice_inplace(&mut values, endianness);
+
+    Ok(values)
+}
+
+/// Reads a length prefix of any integer type from u8 to u128. The prefix is decoded
+/// from exactly `size_of::<PrefixT>()` bytes, independent of the width of usize.
+///
+/// # Errors
+///     io::Error during read
+///     io::Error::UnexpectedEof when the prefix is truncated
+///     io::Error::InvalidData   when a signed prefix is negative or the value does not fit in usize
+pub(crate) fn read_size_prefix<StreamT: Read + ?Sized, PrefixT: PrimInt>(
+    stream: &mut StreamT,
+    endianness: Endianness,
+) -> Result<usize, Error> {
+    let mut buffer: [u8; 16] = [0u8; 16];
+    let width = std::mem::size_of::<PrefixT>();
+    let prefix = buffer.get_mut(..width).ok_or(Error::new(
+        ErrorKind::InvalidInput,
+        "Size prefix types wider than 128 bits are not supported.",
+    ))?;
+
+    stream.read_exact(prefix)?;
+    if endianness == Endianness::Big {
+        prefix.reverse();
+    }
+    let value = u128::from_le_bytes(buffer);
+
+    let signed = PrefixT::min_value() < PrefixT::zero();
+    if signed && (value >> (width * 8 - 1)) & 1 == 1 {
+        return Err(Error::new(ErrorKind::InvalidData, "Size prefix is negative."));
+    }
+
+    usize::try_from(value).or(Err(Error::new(
+        ErrorKind::InvalidData,
+        "Size prefix does not fit in usize.",
+    )))
+}
+
+/// Reads a length prefix and converts it into a number of `element_size` byte elements
+///
+/// # Errors
+///     io::Error::InvalidData when a byte length is not a multiple of `element_size`
+fn read_length_prefix<StreamT: Read + ?Sized, PrefixT: PrimInt>(
+    stream: &mut StreamT,
+    endianness: Endianness,
+    unit: LengthUnit,
+    element_size: usize,
+) -> Result<usize, Error> {
+    let size = read_size_prefix::<StreamT, PrefixT>(stream, endianness)?;
+
+    unit.element_count(size, element_size).ok_or(Error::new(
+        ErrorKind::InvalidData,
+        "Byte length is not a multiple of the element size.",
+    ))
+}
+
+/// Decodes UTF-16 code units. With `detect_bom`, a byte swapped byte order mark
+/// swaps every code unit, and a leading byte order mark is dropped.
+///
+/// # Errors
+///     io::Error::InvalidData when the code units are not valid UTF-16
+fn decode_utf16(mut units: Vec<u16>, detect_bom: bool) -> Result<String, Error> {
+    if detect_bom {
+        if units.first() == Some(&0xFFFE) {
+            units.iter_mut().for_each(|unit| *unit = unit.swap_bytes());
+        }
+        if units.first() == Some(&0xFEFF) {
+            units.remove(0);
+        }
+    }
+
+    String::from_utf16(&units).or(Err(Error::new(
+        ErrorKind::InvalidData,
+        "Unable to convert UTF-16 to string.",
+    )))
+}
+
+/// Decodes UTF-32 code units. With `detect_bom`, a byte swapped byte order mark
+/// swaps every code unit, and a leading byte order mark is dropped.
+///
+/// # Errors
+///     io::Error::InvalidData when the code units are not valid UTF-32
+fn decode_utf32(mut units: Vec<u32>, detect_bom: bool) -> Result<String, Error> {
+    if detect_bom {
+        if units.first() == Some(&0xFFFE0000) {
+            units.iter_mut().for_each(|unit| *unit = unit.swap_bytes());
+        }
+        if units.first() == Some(&0xFEFF) {
+            units.remove(0);
+        }
+    }
+
+    units
+        .into_iter()
+        .map(char::from_u32)
+        .collect::<Option<String>>()
+        .ok_or(Error::new(
+            ErrorKind::InvalidData,
+            "Unable to convert UTF-32 to string.",
+        ))
+}
+
+fn varint_overflow() -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        "Variable length integer overflows its type.",
+    )
+}
+
+fn varint_too_long() -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        "Variable length integer is longer than the maximum length.",
+    )
+}
+
+/// Assemble an unsigned integer from at most 8 bytes stored in `endianness` order
+fn unsigned_from_bytes(bytes: &[u8], endianness: Endianness) -> u64 {
+    let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+    match endianness {
+        Endianness::Little => bytes.iter().rev().fold(0, fold),
+        Endianness::Big => bytes.iter().fold(0, fold),
+    }
 }
@@ -0,0 +1,591 @@
+use std::io::{self, Error, ErrorKind, Read, Seek, Write};
+use std::mem::MaybeUninit;
+
+use half::{bf16, f16};
+
+use super::binary_reader::BinaryReader;
+use super::binary_utils::BinaryUtils;
+use super::binary_writer::BinaryWriter;
+use super::endianness::Endianness;
+use super::error;
+
+/// A type that can be parsed from a binary stream as a whole.
+///
+/// This is the trait produced by `#[derive(BinRead)]`. Every field of a derived
+/// type must itself implement `BinRead`, which bottoms out in the primitive
+/// implementations below that forward to the `BinaryReader` methods. Arrays, tuples
+/// and `Vec` compose from their elements, so user implementations nest in them too.
+/// `BinaryReader::read_value` is the method form of `read_from`.
+pub trait BinRead: Sized {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self>;
+}
+
+/// A type that can be serialized to a binary stream as a whole.
+///
+/// This is the trait produced by `#[derive(BinWrite)]`. Every field of a derived
+/// type must itself implement `BinWrite`, which bottoms out in the primitive
+/// implementations below that forward to the `BinaryWriter` methods.
+///
+/// `write_to` only needs `Write`, so values can go to a `Vec<u8>`, a socket or a
+/// compressor. Types that have to move around in the stream (EG derived types with
+/// `align`) also implement `write_to_seekable`, which containers forward to their
+/// elements, and refuse `write_to` with `ErrorKind::Unsupported`.
+/// `BinaryWriter::write_value` and `SeekableWriter::write_value_seekable` are the
+/// method forms of the two.
+pub trait BinWrite {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()>;
+
+    /// Write to a stream that can also seek. Defaults to `write_to`.
+    ///
+    /// # Parameters
+    ///     `stream`     - The stream to write to
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from writing or seeking
+    fn write_to_seekable<StreamT: Write + Seek>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.write_to(stream, endianness)
+    }
+}
+
+// ------------------------------------------------------------------------------- BinRead
+impl BinRead for u8 {
+    fn read_from<StreamT: Read + Seek>(stream: &mut StreamT, _: Endianness) -> io::Result<Self> {
+        stream.read_u8()
+    }
+}
+
+impl BinRead for i8 {
+    fn read_from<StreamT: Read + Seek>(stream: &mut StreamT, _: Endianness) -> io::Result<Self> {
+        stream.read_i8()
+    }
+}
+
+impl BinRead for u16 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_u16(endianness)
+    }
+}
+
+impl BinRead for i16 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_i16(endianness)
+    }
+}
+
+impl BinRead for u32 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_u32(endianness)
+    }
+}
+
+impl BinRead for i32 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_i32(endianness)
+    }
+}
+
+impl BinRead for u64 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_u64(endianness)
+    }
+}
+
+impl BinRead for i64 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_i64(endianness)
+    }
+}
+
+impl BinRead for u128 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_u128(endianness)
+    }
+}
+
+impl BinRead for i128 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_i128(endianness)
+    }
+}
+
+impl BinRead for f32 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_f32(endianness)
+    }
+}
+
+impl BinRead for f64 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_f64(endianness)
+    }
+}
+
+impl BinRead for f16 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_f16(endianness)
+    }
+}
+
+impl BinRead for bf16 {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        stream.read_bf16(endianness)
+    }
+}
+
+// ------------------------------------------------------------------------------- BinWrite
+impl BinWrite for u8 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        _: Endianness,
+    ) -> io::Result<()> {
+        stream.write_u8(*self)
+    }
+}
+
+impl BinWrite for i8 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        _: Endianness,
+    ) -> io::Result<()> {
+        stream.write_i8(*self)
+    }
+}
+
+impl BinWrite for u16 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_u16(*self, endianness)
+    }
+}
+
+impl BinWrite for i16 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_i16(*self, endianness)
+    }
+}
+
+impl BinWrite for u32 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_u32(*self, endianness)
+    }
+}
+
+impl BinWrite for i32 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_i32(*self, endianness)
+    }
+}
+
+impl BinWrite for u64 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_u64(*self, endianness)
+    }
+}
+
+impl BinWrite for i64 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_i64(*self, endianness)
+    }
+}
+
+impl BinWrite for u128 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_u128(*self, endianness)
+    }
+}
+
+impl BinWrite for i128 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_i128(*self, endianness)
+    }
+}
+
+impl BinWrite for f32 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_f32(*self, endianness)
+    }
+}
+
+impl BinWrite for f64 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_f64(*self, endianness)
+    }
+}
+
+impl BinWrite for f16 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_f16(*self, endianness)
+    }
+}
+
+impl BinWrite for bf16 {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        stream.write_bf16(*self, endianness)
+    }
+}
+
+// ------------------------------------------------------------------------------- Strings
+/// A `String` on its own has no length information, so it is read as a null
+/// terminated string. Use `#[binre(count = ...)]` in a derive for fixed size strings.
+impl BinRead for String {
+    fn read_from<StreamT: Read + Seek>(stream: &mut StreamT, _: Endianness) -> io::Result<Self> {
+        stream.read_null_terminated_string()
+    }
+}
+
+/// Written as a null terminated string, mirroring the `BinRead` implementation.
+impl BinWrite for String {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        _: Endianness,
+    ) -> io::Result<()> {
+        stream.write_nt_string(self)
+    }
+}
+
+// ------------------------------------------------------------------------------- bool
+/// Stored as a single byte, 0 or 1.
+impl BinRead for bool {
+    fn read_from<StreamT: Read + Seek>(stream: &mut StreamT, _: Endianness) -> io::Result<Self> {
+        match stream.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::new(ErrorKind::InvalidData, "Invalid boolean value.")),
+        }
+    }
+}
+
+impl BinWrite for bool {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        _: Endianness,
+    ) -> io::Result<()> {
+        stream.write_u8(*self as u8)
+    }
+}
+
+// ------------------------------------------------------------------------------- Arrays
+// Sequences read and write their elements inside `BinaryUtils::with_context`, so
+// errors carry the index of the failing element.
+impl<T: BinRead, const N: usize> BinRead for [T; N] {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        let mut elements = PartialArray::<T, N>::new();
+        for index in 0..N {
+            let element = stream.with_context(index, |stream| T::read_from(stream, endianness))?;
+            elements.push(element);
+        }
+
+        Ok(elements.into_array())
+    }
+}
+
+/// An array filled one element at a time. Elements read before a failure are dropped
+/// with it, without requiring `T: Default` or a temporary `Vec`.
+struct PartialArray<T, const N: usize> {
+    elements: [MaybeUninit<T>; N],
+    length: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    fn new() -> Self {
+        PartialArray {
+            elements: [const { MaybeUninit::uninit() }; N],
+            length: 0,
+        }
+    }
+
+    /// Only called N times by `read_from`
+    fn push(&mut self, element: T) {
+        self.elements[self.length].write(element);
+        self.length += 1;
+    }
+
+    fn into_array(self) -> [T; N] {
+        debug_assert_eq!(self.length, N);
+        let elements = std::mem::ManuallyDrop::new(self);
+
+        // SAFETY: all N elements are initialized, and `MaybeUninit<T>` has the same
+        // layout as `T`. The elements are moved out and the guard is not dropped.
+        unsafe { std::ptr::read(elements.elements.as_ptr().cast::<[T; N]>()) }
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        for element in &mut self.elements[..self.length] {
+            // SAFETY: the first `length` elements are initialized
+            unsafe { element.assume_init_drop() };
+        }
+    }
+}
+
+impl<T: BinWrite, const N: usize> BinWrite for [T; N] {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.as_slice().write_to(stream, endianness)
+    }
+
+    fn write_to_seekable<StreamT: Write + Seek>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.as_slice().write_to_seekable(stream, endianness)
+    }
+}
+
+/// Without `Seek` errors carry the index of the failing element, but no offset
+impl<T: BinWrite> BinWrite for [T] {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        for (index, element) in self.iter().enumerate() {
+            error::Error::add_context(element.write_to(stream, endianness), index)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_to_seekable<StreamT: Write + Seek>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        for (index, element) in self.iter().enumerate() {
+            stream.with_context(index, |stream| element.write_to_seekable(stream, endianness))?;
+        }
+
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------- Vec
+/// A `Vec` on its own has no length information, so it reads elements until the end
+/// of the stream, consuming everything after it. Use `BinaryReader::read_vec` or
+/// `#[binre(count = ...)]` in a derive for counted vectors. Elements that take up no
+/// bytes (EG zero sized types) can't reach the end and are refused.
+impl<T: BinRead> BinRead for Vec<T> {
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        let end = stream.buffer_size()? as u64;
+
+        let mut elements = Vec::new();
+        let mut position = stream.tell()?;
+        while position < end {
+            let index = elements.len();
+            elements.push(stream.with_context(index, |stream| T::read_from(stream, endianness))?);
+
+            let next = stream.tell()?;
+            if next == position {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Vec element read no data, a count is required.",
+                ));
+            }
+            position = next;
+        }
+
+        Ok(elements)
+    }
+}
+
+impl<T: BinWrite> BinWrite for Vec<T> {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.as_slice().write_to(stream, endianness)
+    }
+
+    fn write_to_seekable<StreamT: Write + Seek>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.as_slice().write_to_seekable(stream, endianness)
+    }
+}
+
+// ------------------------------------------------------------------------------- References
+impl<T: BinWrite + ?Sized> BinWrite for &T {
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        (**self).write_to(stream, endianness)
+    }
+
+    fn write_to_seekable<StreamT: Write + Seek>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        (**self).write_to_seekable(stream, endianness)
+    }
+}
+
+// ------------------------------------------------------------------------------- Tuples
+/// Tuples are read and written element by element, in order. Like arrays, errors
+/// carry the index of the failing element.
+macro_rules! impl_tuple {
+    ($($index:tt $name:ident),+) => {
+        impl<$($name: BinRead),+> BinRead for ($($name,)+) {
+            fn read_from<StreamT: Read + Seek>(
+                stream: &mut StreamT,
+                endianness: Endianness,
+            ) -> io::Result<Self> {
+                Ok(($(
+                    stream.with_context($index, |stream| $name::read_from(stream, endianness))?,
+                )+))
+            }
+        }
+
+        impl<$($name: BinWrite),+> BinWrite for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn write_to<StreamT: Write>(
+                &self,
+                stream: &mut StreamT,
+                endianness: Endianness,
+            ) -> io::Result<()> {
+                let ($($name,)+) = self;
+                $(error::Error::add_context($name.write_to(stream, endianness), $index)?;)+
+
+                Ok(())
+            }
+
+            #[allow(non_snake_case)]
+            fn write_to_seekable<StreamT: Write + Seek>(
+                &self,
+                stream: &mut StreamT,
+                endianness: Endianness,
+            ) -> io::Result<()> {
+                let ($($name,)+) = self;
+                $(
+                    stream.with_context($index, |stream| $name.write_to_seekable(stream, endianness))?;
+                )+
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple!(0 A);
+impl_tuple!(0 A, 1 B);
+impl_tuple!(0 A, 1 B, 2 C);
+impl_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
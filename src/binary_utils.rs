@@ -1,10 +1,35 @@
 use std::io::{self, Error, ErrorKind, Seek};
 
+use super::error::{self, PathSegment};
+use super::rollback_guard::RollbackGuard;
+
 pub trait BinaryUtils: Seek {
     fn ignore(&mut self, length: usize) -> io::Result<()>;
     fn next_alignment(&mut self, alignment: u64) -> io::Result<()>;
     fn tell(&mut self) -> io::Result<u64>;
     fn buffer_size(&mut self) -> io::Result<usize>;
+    fn read_at_offset<T, ReadF>(
+        &mut self,
+        offset: u64,
+        base: u64,
+        read: ReadF,
+    ) -> io::Result<Option<T>>
+    where
+        ReadF: FnOnce(&mut Self) -> io::Result<T>;
+    fn with_context<T, ScopeF>(
+        &mut self,
+        segment: impl Into<PathSegment>,
+        scope: ScopeF,
+    ) -> io::Result<T>
+    where
+        ScopeF: FnOnce(&mut Self) -> io::Result<T>;
+    fn peek<T, ReadF>(&mut self, read: ReadF) -> io::Result<T>
+    where
+        ReadF: FnOnce(&mut Self) -> io::Result<T>;
+    fn with_rollback<T, ScopeF>(&mut self, scope: ScopeF) -> io::Result<T>
+    where
+        ScopeF: FnOnce(&mut Self) -> io::Result<T>;
+    fn rollback_guard(&mut self) -> io::Result<RollbackGuard<'_, Self>>;
 }
 
 impl<StreamT: Seek> BinaryUtils for StreamT {
@@ -85,4 +110,139 @@ impl<StreamT: Seek> BinaryUtils for StreamT {
 
         Ok(())
     }
+
+    /// Follows a file offset: saves the stream position, seeks to `base + offset`,
+    /// runs `read` and then restores the saved position, even if `read` failed.
+    ///
+    /// # Parameters
+    ///     `offset` - The offset read from the stream. Zero is treated as a null offset
+    ///     `base`   - What `offset` is relative to (zero for absolute offsets)
+    ///     `read`   - Parses the value at the offset
+    ///
+    /// # Errors
+    ///     io::Error::InvalidData - `base + offset` is past the end of the stream
+    ///     io::Error              - Error bubbled up from `read` or seeking
+    ///
+    /// # Returns
+    ///     None for a null offset, otherwise the value returned by `read`
+    fn read_at_offset<T, ReadF>(
+        &mut self,
+        offset: u64,
+        base: u64,
+        read: ReadF,
+    ) -> io::Result<Option<T>>
+    where
+        ReadF: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        if offset == 0 {
+            return Ok(None);
+        }
+
+        let stream_size = self.buffer_size()? as u64;
+        let target = base
+            .checked_add(offset)
+            .filter(|target| *target <= stream_size)
+            .ok_or(Error::new(
+                ErrorKind::InvalidData,
+                "Offset is out of range of the stream.",
+            ))?;
+
+        let return_position = self.tell()?;
+        self.seek(io::SeekFrom::Start(target))?;
+        let result = read(self);
+        self.seek(io::SeekFrom::Start(return_position))?;
+
+        result.map(Some)
+    }
+
+    /// Runs `scope` as a named step of a larger read or write. If it fails, the error
+    /// is turned into a `binre::Error` with `segment` as the next outer step of its path.
+    /// An error without an offset yet records the position it surfaced at; the stream
+    /// is only asked for it on this error path.
+    ///
+    /// # Parameters
+    ///     `segment` - Field name or element index describing what `scope` handles
+    ///     `scope`   - The read or write to run
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from `scope`, carrying a `binre::Error`
+    ///
+    /// # Returns
+    ///     The value returned by `scope`
+    fn with_context<T, ScopeF>(
+        &mut self,
+        segment: impl Into<PathSegment>,
+        scope: ScopeF,
+    ) -> io::Result<T>
+    where
+        ScopeF: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        scope(self).map_err(|error| {
+            let mut error = error::Error::from(error);
+            if error.offset().is_none() {
+                if let Ok(position) = self.tell() {
+                    error = error.with_offset(position);
+                }
+            }
+
+            error.with_context(segment).into()
+        })
+    }
+
+    /// Runs `read` and then returns to the position it started at, whether it
+    /// succeeded or not.
+    ///
+    /// # Parameters
+    ///     `read` - Parses the value to look at
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from `read` or seeking
+    ///
+    /// # Returns
+    ///     The value returned by `read`
+    fn peek<T, ReadF>(&mut self, read: ReadF) -> io::Result<T>
+    where
+        ReadF: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        let start = self.tell()?;
+        let result = read(self);
+        self.seek(io::SeekFrom::Start(start))?;
+
+        result
+    }
+
+    /// Runs `scope` and returns to the position it started at if it fails, so another
+    /// layout can be tried (EG `stream.with_rollback(read_v3).or_else(|_| read_v2(stream))`).
+    ///
+    /// # Parameters
+    ///     `scope` - The read to attempt
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from `scope` or seeking
+    ///
+    /// # Returns
+    ///     The value returned by `scope`, with the stream left after it
+    fn with_rollback<T, ScopeF>(&mut self, scope: ScopeF) -> io::Result<T>
+    where
+        ScopeF: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        let start = self.tell()?;
+        let result = scope(self);
+        if result.is_err() {
+            self.seek(io::SeekFrom::Start(start))?;
+        }
+
+        result
+    }
+
+    /// Creates a guard that returns the stream to the current position when it is
+    /// dropped, unless `RollbackGuard::commit` is called first.
+    ///
+    /// # Errors
+    ///     Same errors as std::io::Seek (negative offset, seeking failed)
+    fn rollback_guard(&mut self) -> io::Result<RollbackGuard<'_, Self>> {
+        let start = self.tell()?;
+
+        Ok(RollbackGuard::new(self, start))
+    }
 }
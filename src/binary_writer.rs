@@ -1,7 +1,17 @@
+use super::binary_traits::BinWrite;
+use super::endian_types::{as_bytes, swap_slice_inplace, PlainData};
 use super::endianness::{swap_inplace, Endianness, SYS_ENDIANNESS};
-use std::io::{self, Error, ErrorKind, Seek, Write};
+use super::length_unit::LengthUnit;
+use super::text_encoding::Encoding;
+use half::{bf16, f16};
+use num_traits::{PrimInt, ToBytes};
+use std::io::{self, Error, ErrorKind, Write};
+
+pub trait BinaryWriter: Write {
+    fn write_value<T: BinWrite + ?Sized>(&mut self, value: &T, endianness: Endianness) -> io::Result<()>
+    where
+        Self: Sized;
 
-pub trait BinaryWriter: Write + Seek {
     fn write_u8(&mut self, value: u8) -> io::Result<()>;
     fn write_i8(&mut self, value: i8) -> io::Result<()>;
 
@@ -35,6 +45,36 @@ pub trait BinaryWriter: Write + Seek {
     fn write_le_i64(&mut self, value: i64) -> io::Result<()>;
     fn write_ne_i64(&mut self, value: i64) -> io::Result<()>;
 
+    fn write_u24(&mut self, value: u32, endianness: Endianness) -> io::Result<()>;
+    fn write_be_u24(&mut self, value: u32) -> io::Result<()>;
+    fn write_le_u24(&mut self, value: u32) -> io::Result<()>;
+    fn write_ne_u24(&mut self, value: u32) -> io::Result<()>;
+
+    fn write_i24(&mut self, value: i32, endianness: Endianness) -> io::Result<()>;
+    fn write_be_i24(&mut self, value: i32) -> io::Result<()>;
+    fn write_le_i24(&mut self, value: i32) -> io::Result<()>;
+    fn write_ne_i24(&mut self, value: i32) -> io::Result<()>;
+
+    fn write_u48(&mut self, value: u64, endianness: Endianness) -> io::Result<()>;
+    fn write_be_u48(&mut self, value: u64) -> io::Result<()>;
+    fn write_le_u48(&mut self, value: u64) -> io::Result<()>;
+    fn write_ne_u48(&mut self, value: u64) -> io::Result<()>;
+
+    fn write_i48(&mut self, value: i64, endianness: Endianness) -> io::Result<()>;
+    fn write_be_i48(&mut self, value: i64) -> io::Result<()>;
+    fn write_le_i48(&mut self, value: i64) -> io::Result<()>;
+    fn write_ne_i48(&mut self, value: i64) -> io::Result<()>;
+
+    fn write_u128(&mut self, value: u128, endianness: Endianness) -> io::Result<()>;
+    fn write_be_u128(&mut self, value: u128) -> io::Result<()>;
+    fn write_le_u128(&mut self, value: u128) -> io::Result<()>;
+    fn write_ne_u128(&mut self, value: u128) -> io::Result<()>;
+
+    fn write_i128(&mut self, value: i128, endianness: Endianness) -> io::Result<()>;
+    fn write_be_i128(&mut self, value: i128) -> io::Result<()>;
+    fn write_le_i128(&mut self, value: i128) -> io::Result<()>;
+    fn write_ne_i128(&mut self, value: i128) -> io::Result<()>;
+
     fn write_f32(&mut self, value: f32, endianness: Endianness) -> io::Result<()>;
     fn write_be_f32(&mut self, value: f32) -> io::Result<()>;
     fn write_le_f32(&mut self, value: f32) -> io::Result<()>;
@@ -45,11 +85,126 @@ pub trait BinaryWriter: Write + Seek {
     fn write_le_f64(&mut self, value: f64) -> io::Result<()>;
     fn write_ne_f64(&mut self, value: f64) -> io::Result<()>;
 
+    fn write_f16(&mut self, value: f16, endianness: Endianness) -> io::Result<()>;
+    fn write_be_f16(&mut self, value: f16) -> io::Result<()>;
+    fn write_le_f16(&mut self, value: f16) -> io::Result<()>;
+    fn write_ne_f16(&mut self, value: f16) -> io::Result<()>;
+
+    fn write_bf16(&mut self, value: bf16, endianness: Endianness) -> io::Result<()>;
+    fn write_be_bf16(&mut self, value: bf16) -> io::Result<()>;
+    fn write_le_bf16(&mut self, value: bf16) -> io::Result<()>;
+    fn write_ne_bf16(&mut self, value: bf16) -> io::Result<()>;
+
+    fn write_fixed_point<IntT>(
+        &mut self,
+        value: f64,
+        fractional_bits: u32,
+        endianness: Endianness,
+    ) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized;
+    fn write_be_fixed_point<IntT>(&mut self, value: f64, fractional_bits: u32) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized;
+    fn write_le_fixed_point<IntT>(&mut self, value: f64, fractional_bits: u32) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized;
+    fn write_ne_fixed_point<IntT>(&mut self, value: f64, fractional_bits: u32) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized;
+
+    fn write_u8_slice(&mut self, values: &[u8]) -> io::Result<()>;
+    fn write_i8_slice(&mut self, values: &[i8]) -> io::Result<()>;
+    fn write_u16_slice(&mut self, values: &[u16], endianness: Endianness) -> io::Result<()>;
+    fn write_i16_slice(&mut self, values: &[i16], endianness: Endianness) -> io::Result<()>;
+    fn write_u32_slice(&mut self, values: &[u32], endianness: Endianness) -> io::Result<()>;
+    fn write_i32_slice(&mut self, values: &[i32], endianness: Endianness) -> io::Result<()>;
+    fn write_u64_slice(&mut self, values: &[u64], endianness: Endianness) -> io::Result<()>;
+    fn write_i64_slice(&mut self, values: &[i64], endianness: Endianness) -> io::Result<()>;
+    fn write_u128_slice(&mut self, values: &[u128], endianness: Endianness) -> io::Result<()>;
+    fn write_i128_slice(&mut self, values: &[i128], endianness: Endianness) -> io::Result<()>;
+    fn write_f16_slice(&mut self, values: &[f16], endianness: Endianness) -> io::Result<()>;
+    fn write_bf16_slice(&mut self, values: &[bf16], endianness: Endianness) -> io::Result<()>;
+    fn write_f32_slice(&mut self, values: &[f32], endianness: Endianness) -> io::Result<()>;
+    fn write_f64_slice(&mut self, values: &[f64], endianness: Endianness) -> io::Result<()>;
+
     fn write_string(&mut self, value: &str) -> io::Result<()>;
     fn write_nt_string(&mut self, value: &str) -> io::Result<()>;
+    fn write_size_prefixed_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+    ) -> io::Result<()>;
+    fn write_size_prefixed_bytes<PrefixT: PrimInt>(
+        &mut self,
+        value: &[u8],
+        endianness: Endianness,
+    ) -> io::Result<()>;
+
+    fn write_uleb128(&mut self, value: u64) -> io::Result<()>;
+    fn write_sleb128(&mut self, value: i64) -> io::Result<()>;
+    fn write_zigzag_varint(&mut self, value: i64) -> io::Result<()>;
+    fn write_vlq(&mut self, value: u64) -> io::Result<()>;
+    fn write_7bit_encoded_int(&mut self, value: i32) -> io::Result<()>;
+
+    fn write_encoded_string(&mut self, value: &str, encoding: Encoding) -> io::Result<()>;
+    fn write_nt_encoded_string(&mut self, value: &str, encoding: Encoding) -> io::Result<()>;
+    fn write_size_prefixed_encoded_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+        encoding: Encoding,
+    ) -> io::Result<()>;
+
+    fn write_utf16_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()>;
+    fn write_nt_utf16_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()>;
+    fn write_size_prefixed_utf16_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> io::Result<()>;
+
+    fn write_utf32_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()>;
+    fn write_nt_utf32_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()>;
+    fn write_size_prefixed_utf32_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> io::Result<()>;
+
+    fn write_padding(&mut self, length: usize, fill: &[u8]) -> io::Result<()>;
 }
 
-impl<StreamT: Write + Seek> BinaryWriter for StreamT {
+impl<StreamT: Write> BinaryWriter for StreamT {
+    /// Write any `BinWrite` type to stream. Named `write_value` rather than `write`
+    /// so it does not clash with `Write::write`.
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// This goes through `BinWrite::write_to`, so it works on any `Write`, but types
+    /// that need to seek (derived types with `align`) fail with `Unsupported` even
+    /// when the stream could seek. Write those with
+    /// `SeekableWriter::write_value_seekable`.
+    ///
+    /// # Errors
+    ///     io::Error              - Whatever the type's `BinWrite` implementation reports
+    ///     io::Error::Unsupported - When the type needs to seek, use
+    ///                              `SeekableWriter::write_value_seekable` instead
+    fn write_value<T: BinWrite + ?Sized>(&mut self, value: &T, endianness: Endianness) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        value.write_to(self, endianness)
+    }
+
     /// Write an u8 to stream
     ///
     /// # Parameters
@@ -57,11 +212,9 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_u8(&mut self, value: u8) -> io::Result<()> {
-        if self.write(&value.to_ne_bytes())? != std::mem::size_of::<u8>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&value.to_ne_bytes())?;
 
         Ok(())
     }
@@ -73,11 +226,9 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_i8(&mut self, value: i8) -> io::Result<()> {
-        if self.write(&value.to_ne_bytes())? != std::mem::size_of::<i8>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&value.to_ne_bytes())?;
 
         Ok(())
     }
@@ -90,14 +241,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_u16(&mut self, value: u16, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 2] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<u16>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -109,7 +258,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_u16(&mut self, value: u16) -> io::Result<()> {
         self.write_u16(value, Endianness::Big)
     }
@@ -122,7 +271,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_u16(&mut self, value: u16) -> io::Result<()> {
         self.write_u16(value, Endianness::Little)
     }
@@ -135,7 +284,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_u16(&mut self, value: u16) -> io::Result<()> {
         self.write_u16(value, *SYS_ENDIANNESS)
     }
@@ -148,14 +297,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_i16(&mut self, value: i16, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 2] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<i16>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -168,7 +315,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_i16(&mut self, value: i16) -> io::Result<()> {
         self.write_i16(value, Endianness::Big)
     }
@@ -181,7 +328,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_i16(&mut self, value: i16) -> io::Result<()> {
         self.write_i16(value, Endianness::Little)
     }
@@ -194,7 +341,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_i16(&mut self, value: i16) -> io::Result<()> {
         self.write_i16(value, *SYS_ENDIANNESS)
     }
@@ -207,14 +354,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_u32(&mut self, value: u32, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 4] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<u32>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -227,7 +372,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_u32(&mut self, value: u32) -> io::Result<()> {
         self.write_u32(value, Endianness::Big)
     }
@@ -240,7 +385,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_u32(&mut self, value: u32) -> io::Result<()> {
         self.write_u32(value, Endianness::Little)
     }
@@ -253,7 +398,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_u32(&mut self, value: u32) -> io::Result<()> {
         self.write_u32(value, *SYS_ENDIANNESS)
     }
@@ -266,14 +411,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_i32(&mut self, value: i32, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 4] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<i32>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -286,7 +429,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_i32(&mut self, value: i32) -> io::Result<()> {
         self.write_i32(value, Endianness::Big)
     }
@@ -299,7 +442,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_i32(&mut self, value: i32) -> io::Result<()> {
         self.write_i32(value, Endianness::Little)
     }
@@ -312,7 +455,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_i32(&mut self, value: i32) -> io::Result<()> {
         self.write_i32(value, *SYS_ENDIANNESS)
     }
@@ -325,14 +468,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_u64(&mut self, value: u64, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 8] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<u64>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -345,7 +486,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_u64(&mut self, value: u64) -> io::Result<()> {
         self.write_u64(value, Endianness::Big)
     }
@@ -358,7 +499,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_u64(&mut self, value: u64) -> io::Result<()> {
         self.write_u64(value, Endianness::Little)
     }
@@ -371,7 +512,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_u64(&mut self, value: u64) -> io::Result<()> {
         self.write_u64(value, *SYS_ENDIANNESS)
     }
@@ -384,14 +525,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_i64(&mut self, value: i64, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 8] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<i64>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -404,7 +543,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_i64(&mut self, value: i64) -> io::Result<()> {
         self.write_i64(value, Endianness::Big)
     }
@@ -417,7 +556,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_i64(&mut self, value: i64) -> io::Result<()> {
         self.write_i64(value, Endianness::Little)
     }
@@ -430,11 +569,321 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_i64(&mut self, value: i64) -> io::Result<()> {
         self.write_i64(value, *SYS_ENDIANNESS)
     }
 
+    /// Write a u24 (stored in 3 bytes) to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_u24(&mut self, value: u32, endianness: Endianness) -> io::Result<()> {
+        if value >> 24 != 0 {
+            return Err(out_of_range(24));
+        }
+
+        self.write_all(&unsigned_to_bytes(value as u64, 3, endianness))
+    }
+
+    /// Write a little endian u24 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_le_u24(&mut self, value: u32) -> io::Result<()> {
+        self.write_u24(value, Endianness::Little)
+    }
+
+    /// Write a big endian u24 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_be_u24(&mut self, value: u32) -> io::Result<()> {
+        self.write_u24(value, Endianness::Big)
+    }
+
+    /// Write a native endian u24 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_ne_u24(&mut self, value: u32) -> io::Result<()> {
+        self.write_u24(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write an i24 (stored in 3 bytes) to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_i24(&mut self, value: i32, endianness: Endianness) -> io::Result<()> {
+        if (value << 8) >> 8 != value {
+            return Err(out_of_range(24));
+        }
+
+        let mask = u64::MAX >> (u64::BITS - 24);
+        self.write_all(&unsigned_to_bytes(value as u64 & mask, 3, endianness))
+    }
+
+    /// Write a little endian i24 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_le_i24(&mut self, value: i32) -> io::Result<()> {
+        self.write_i24(value, Endianness::Little)
+    }
+
+    /// Write a big endian i24 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_be_i24(&mut self, value: i32) -> io::Result<()> {
+        self.write_i24(value, Endianness::Big)
+    }
+
+    /// Write a native endian i24 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 24 bits
+    fn write_ne_i24(&mut self, value: i32) -> io::Result<()> {
+        self.write_i24(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write a u48 (stored in 6 bytes) to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_u48(&mut self, value: u64, endianness: Endianness) -> io::Result<()> {
+        if value >> 48 != 0 {
+            return Err(out_of_range(48));
+        }
+
+        self.write_all(&unsigned_to_bytes(value, 6, endianness))
+    }
+
+    /// Write a little endian u48 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_le_u48(&mut self, value: u64) -> io::Result<()> {
+        self.write_u48(value, Endianness::Little)
+    }
+
+    /// Write a big endian u48 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_be_u48(&mut self, value: u64) -> io::Result<()> {
+        self.write_u48(value, Endianness::Big)
+    }
+
+    /// Write a native endian u48 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_ne_u48(&mut self, value: u64) -> io::Result<()> {
+        self.write_u48(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write an i48 (stored in 6 bytes) to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_i48(&mut self, value: i64, endianness: Endianness) -> io::Result<()> {
+        if (value << 16) >> 16 != value {
+            return Err(out_of_range(48));
+        }
+
+        let mask = u64::MAX >> (u64::BITS - 48);
+        self.write_all(&unsigned_to_bytes(value as u64 & mask, 6, endianness))
+    }
+
+    /// Write a little endian i48 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_le_i48(&mut self, value: i64) -> io::Result<()> {
+        self.write_i48(value, Endianness::Little)
+    }
+
+    /// Write a big endian i48 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_be_i48(&mut self, value: i64) -> io::Result<()> {
+        self.write_i48(value, Endianness::Big)
+    }
+
+    /// Write a native endian i48 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value does not fit in 48 bits
+    fn write_ne_i48(&mut self, value: i64) -> io::Result<()> {
+        self.write_i48(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write a u128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_u128(&mut self, value: u128, endianness: Endianness) -> io::Result<()> {
+        let mut bytes: [u8; 16] = value.to_ne_bytes();
+        swap_inplace(bytes.as_mut_slice(), endianness);
+
+        self.write_all(&bytes)
+    }
+
+    /// Write a little endian u128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_le_u128(&mut self, value: u128) -> io::Result<()> {
+        self.write_u128(value, Endianness::Little)
+    }
+
+    /// Write a big endian u128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_be_u128(&mut self, value: u128) -> io::Result<()> {
+        self.write_u128(value, Endianness::Big)
+    }
+
+    /// Write a native endian u128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_ne_u128(&mut self, value: u128) -> io::Result<()> {
+        self.write_u128(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write an i128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_i128(&mut self, value: i128, endianness: Endianness) -> io::Result<()> {
+        let mut bytes: [u8; 16] = value.to_ne_bytes();
+        swap_inplace(bytes.as_mut_slice(), endianness);
+
+        self.write_all(&bytes)
+    }
+
+    /// Write a little endian i128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_le_i128(&mut self, value: i128) -> io::Result<()> {
+        self.write_i128(value, Endianness::Little)
+    }
+
+    /// Write a big endian i128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_be_i128(&mut self, value: i128) -> io::Result<()> {
+        self.write_i128(value, Endianness::Big)
+    }
+
+    /// Write a native endian i128 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_ne_i128(&mut self, value: i128) -> io::Result<()> {
+        self.write_i128(value, *SYS_ENDIANNESS)
+    }
+
     /// Write an f32 to stream
     ///
     /// # Parameters
@@ -443,14 +892,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_f32(&mut self, value: f32, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 4] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<f32>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -463,7 +910,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_f32(&mut self, value: f32) -> io::Result<()> {
         self.write_f32(value, Endianness::Big)
     }
@@ -476,7 +923,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_f32(&mut self, value: f32) -> io::Result<()> {
         self.write_f32(value, Endianness::Little)
     }
@@ -489,7 +936,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_f32(&mut self, value: f32) -> io::Result<()> {
         self.write_f32(value, *SYS_ENDIANNESS)
     }
@@ -502,14 +949,12 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_f64(&mut self, value: f64, endianness: Endianness) -> io::Result<()> {
         let mut bytes: [u8; 8] = value.to_ne_bytes();
         swap_inplace(bytes.as_mut_slice(), endianness);
 
-        if self.write(&bytes)? != std::mem::size_of::<f64>() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+        self.write_all(&bytes)?;
 
         Ok(())
     }
@@ -522,7 +967,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_be_f64(&mut self, value: f64) -> io::Result<()> {
         self.write_f64(value, Endianness::Big)
     }
@@ -535,7 +980,7 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_le_f64(&mut self, value: f64) -> io::Result<()> {
         self.write_f64(value, Endianness::Little)
     }
@@ -548,43 +993,811 @@ impl<StreamT: Write + Seek> BinaryWriter for StreamT {
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
     fn write_ne_f64(&mut self, value: f64) -> io::Result<()> {
         self.write_f64(value, *SYS_ENDIANNESS)
     }
 
-
-    /// Write a fixed size string to stream
+    /// Write a f16 to stream
     ///
     /// # Parameters
     ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
-    fn write_string(&mut self, value: &str) -> io::Result<()> {
-        if self.write(value.as_bytes())? != value.len() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
+    fn write_f16(&mut self, value: f16, endianness: Endianness) -> io::Result<()> {
+        let mut bytes: [u8; 2] = value.to_ne_bytes();
+        swap_inplace(bytes.as_mut_slice(), endianness);
 
-        Ok(())
+        self.write_all(&bytes)
     }
 
+    /// Write a little endian f16 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_le_f16(&mut self, value: f16) -> io::Result<()> {
+        self.write_f16(value, Endianness::Little)
+    }
 
-    /// Write a null terminated string to stream
+    /// Write a big endian f16 to stream
     ///
     /// # Parameters
     ///     `value` - The variable to write to the stream
     ///
     /// # Errors
     ///     io::Error during write
-    ///     io::Error::UnexpectedEof when filestream could not write full buffer
-    fn write_nt_string(&mut self, value: &str) -> io::Result<()> {
-        if self.write(value.as_bytes())? != value.len() {
-            return Err(Error::from(ErrorKind::UnexpectedEof));
-        }
-        self.write_u8(0u8)?;
+    fn write_be_f16(&mut self, value: f16) -> io::Result<()> {
+        self.write_f16(value, Endianness::Big)
+    }
+
+    /// Write a native endian f16 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_ne_f16(&mut self, value: f16) -> io::Result<()> {
+        self.write_f16(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write a bf16 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_bf16(&mut self, value: bf16, endianness: Endianness) -> io::Result<()> {
+        let mut bytes: [u8; 2] = value.to_ne_bytes();
+        swap_inplace(bytes.as_mut_slice(), endianness);
+
+        self.write_all(&bytes)
+    }
+
+    /// Write a little endian bf16 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_le_bf16(&mut self, value: bf16) -> io::Result<()> {
+        self.write_bf16(value, Endianness::Little)
+    }
+
+    /// Write a big endian bf16 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_be_bf16(&mut self, value: bf16) -> io::Result<()> {
+        self.write_bf16(value, Endianness::Big)
+    }
+
+    /// Write a native endian bf16 to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_ne_bf16(&mut self, value: bf16) -> io::Result<()> {
+        self.write_bf16(value, *SYS_ENDIANNESS)
+    }
+
+    /// Write a fixed point number stored as an `IntT` with `fractional_bits` fractional bits.
+    /// The value is rounded to the nearest representable step.
+    ///
+    /// # Parameters
+    ///     `value`           - The variable to write to the stream
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///     `endianness`      - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value is out of range of the fixed point type
+    fn write_fixed_point<IntT>(
+        &mut self,
+        value: f64,
+        fractional_bits: u32,
+        endianness: Endianness,
+    ) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized,
+    {
+        let scaled = (value * 2f64.powi(fractional_bits as i32)).round();
+        let raw = <IntT as num_traits::NumCast>::from(scaled).ok_or(Error::new(
+            ErrorKind::InvalidInput,
+            "Value does not fit in the fixed point type.",
+        ))?;
+
+        let mut bytes = raw.to_ne_bytes();
+        swap_inplace(bytes.as_mut(), endianness);
+
+        self.write_all(bytes.as_ref())
+    }
+
+    /// Write a little endian fixed point number to stream
+    ///
+    /// # Parameters
+    ///     `value`           - The variable to write to the stream
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value is out of range of the fixed point type
+    fn write_le_fixed_point<IntT>(&mut self, value: f64, fractional_bits: u32) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized,
+    {
+        self.write_fixed_point::<IntT>(value, fractional_bits, Endianness::Little)
+    }
+
+    /// Write a big endian fixed point number to stream
+    ///
+    /// # Parameters
+    ///     `value`           - The variable to write to the stream
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value is out of range of the fixed point type
+    fn write_be_fixed_point<IntT>(&mut self, value: f64, fractional_bits: u32) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized,
+    {
+        self.write_fixed_point::<IntT>(value, fractional_bits, Endianness::Big)
+    }
+
+    /// Write a native endian fixed point number to stream
+    ///
+    /// # Parameters
+    ///     `value`           - The variable to write to the stream
+    ///     `fractional_bits` - Number of bits after the binary point
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the value is out of range of the fixed point type
+    fn write_ne_fixed_point<IntT>(&mut self, value: f64, fractional_bits: u32) -> io::Result<()>
+    where
+        IntT: PrimInt + ToBytes,
+        IntT::Bytes: Sized,
+    {
+        self.write_fixed_point::<IntT>(value, fractional_bits, *SYS_ENDIANNESS)
+    }
+
+    /// Write u8 values to stream using a single write
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_u8_slice(&mut self, values: &[u8]) -> io::Result<()> {
+        self.write_all(values)
+    }
+
+    /// Write i8 values to stream using a single write
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_i8_slice(&mut self, values: &[i8]) -> io::Result<()> {
+        self.write_all(as_bytes(values))
+    }
+
+    /// Write u16 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_u16_slice(&mut self, values: &[u16], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write i16 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_i16_slice(&mut self, values: &[i16], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write u32 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_u32_slice(&mut self, values: &[u32], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write i32 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_i32_slice(&mut self, values: &[i32], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write u64 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_u64_slice(&mut self, values: &[u64], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write i64 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_i64_slice(&mut self, values: &[i64], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write u128 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_u128_slice(&mut self, values: &[u128], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write i128 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_i128_slice(&mut self, values: &[i128], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write f16 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_f16_slice(&mut self, values: &[f16], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write bf16 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_bf16_slice(&mut self, values: &[bf16], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write f32 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_f32_slice(&mut self, values: &[f32], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write f64 values to stream using a single write, swapping a copy only when `endianness` differs from the system's
+    ///
+    /// # Parameters
+    ///     `values` - The values to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_f64_slice(&mut self, values: &[f64], endianness: Endianness) -> io::Result<()> {
+        write_plain_slice(self, values, endianness)
+    }
+
+    /// Write a fixed size string to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_string(&mut self, value: &str) -> io::Result<()> {
+        self.write_all(value.as_bytes())?;
+
+        Ok(())
+    }
+
+
+    /// Write a null terminated string to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_nt_string(&mut self, value: &str) -> io::Result<()> {
+        self.write_all(value.as_bytes())?;
+        self.write_u8(0u8)?;
 
         Ok(())
     }
+
+    /// Write a size prefixed string to stream. The prefix holds the length in bytes.
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the size prefix
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the length of `value` does not fit in `PrefixT`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_size_prefixed_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.write_size_prefixed_bytes::<PrefixT>(value.as_bytes(), endianness)
+    }
+
+    /// Write a size prefixed blob of bytes to stream
+    ///
+    /// # Parameters
+    ///     `value`      - The bytes to write to the stream
+    ///     `endianness` - Endianness of the size prefix
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the length of `value` does not fit in `PrefixT`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_size_prefixed_bytes<PrefixT: PrimInt>(
+        &mut self,
+        value: &[u8],
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        write_size_prefix::<Self, PrefixT>(self, value.len(), endianness)?;
+        self.write_all(value)
+    }
+
+    /// Write an unsigned LEB128 variable length integer to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_uleb128(&mut self, mut value: u64) -> io::Result<()> {
+        let mut buffer: Vec<u8> = Vec::with_capacity(10);
+        loop {
+            let group = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                buffer.push(group);
+                break;
+            }
+            buffer.push(group | 0x80);
+        }
+
+        self.write_all(&buffer)
+    }
+
+    /// Write a signed LEB128 variable length integer to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_sleb128(&mut self, mut value: i64) -> io::Result<()> {
+        let mut buffer: Vec<u8> = Vec::with_capacity(10);
+        loop {
+            let group = (value & 0x7F) as u8;
+            value >>= 7;
+            let sign_bit = group & 0x40 != 0;
+            if (value == 0 && !sign_bit) || (value == -1 && sign_bit) {
+                buffer.push(group);
+                break;
+            }
+            buffer.push(group | 0x80);
+        }
+
+        self.write_all(&buffer)
+    }
+
+    /// Write a zigzag encoded signed LEB128 variable length integer to stream
+    /// (as used by Protocol Buffers `sint` fields)
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_zigzag_varint(&mut self, value: i64) -> io::Result<()> {
+        self.write_uleb128(((value << 1) ^ (value >> 63)) as u64)
+    }
+
+    /// Write a big endian variable length quantity (as used by MIDI) to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_vlq(&mut self, value: u64) -> io::Result<()> {
+        let mut buffer: Vec<u8> = vec![(value & 0x7F) as u8];
+        let mut remaining = value >> 7;
+        while remaining != 0 {
+            buffer.push((remaining & 0x7F) as u8 | 0x80);
+            remaining >>= 7;
+        }
+        buffer.reverse();
+
+        self.write_all(&buffer)
+    }
+
+    /// Write a 7 bit encoded int (as read by .NET's BinaryReader) to stream
+    ///
+    /// # Parameters
+    ///     `value` - The variable to write to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    fn write_7bit_encoded_int(&mut self, value: i32) -> io::Result<()> {
+        self.write_uleb128(value as u32 as u64)
+    }
+
+    /// Write a string in a specific encoding to stream, without a terminator
+    ///
+    /// # Parameters
+    ///     `value`    - The variable to write to the stream
+    ///     `encoding` - The character encoding to write the string in
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when `value` can not be represented exactly in `encoding`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_encoded_string(&mut self, value: &str, encoding: Encoding) -> io::Result<()> {
+        self.write_all(&encoding.encode(value)?)
+    }
+
+    /// Write a null terminated string in a specific encoding to stream
+    ///
+    /// # Parameters
+    ///     `value`    - The variable to write to the stream
+    ///     `encoding` - The character encoding to write the string in
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when `value` can not be represented exactly in `encoding`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_nt_encoded_string(&mut self, value: &str, encoding: Encoding) -> io::Result<()> {
+        self.write_encoded_string(value, encoding)?;
+        self.write_u8(0u8)
+    }
+
+    /// Write a size prefixed string in a specific encoding to stream. The prefix
+    /// holds the length of the encoded string in bytes.
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the size prefix
+    ///     `encoding`   - The character encoding to write the string in
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when `value` can not be represented exactly in `encoding`, or its length does not fit in `PrefixT`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_size_prefixed_encoded_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+        encoding: Encoding,
+    ) -> io::Result<()> {
+        self.write_size_prefixed_bytes::<PrefixT>(&encoding.encode(value)?, endianness)
+    }
+
+    /// Write a UTF-16 string to stream, without a terminator
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the code units
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_utf16_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()> {
+        self.write_all(&encode_utf16(value, endianness))
+    }
+
+    /// Write a UTF-16 string to stream followed by a zero code unit
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the code units
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_nt_utf16_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()> {
+        self.write_utf16_string(value, endianness)?;
+        self.write_u16(0u16, endianness)
+    }
+
+    /// Write a size prefixed UTF-16 string to stream
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the prefix and the code units
+    ///     `unit`       - Whether the prefix counts bytes or code units
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the length of `value` does not fit in `PrefixT`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_size_prefixed_utf16_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> io::Result<()> {
+        let bytes = encode_utf16(value, endianness);
+        write_length_prefix::<Self, PrefixT>(self, bytes.len() / 2, 2, unit, endianness)?;
+        self.write_all(&bytes)
+    }
+
+    /// Write a UTF-32 string to stream, without a terminator
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the code units
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_utf32_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()> {
+        self.write_all(&encode_utf32(value, endianness))
+    }
+
+    /// Write a UTF-32 string to stream followed by a zero code unit
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the code units
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_nt_utf32_string(&mut self, value: &str, endianness: Endianness) -> io::Result<()> {
+        self.write_utf32_string(value, endianness)?;
+        self.write_u32(0u32, endianness)
+    }
+
+    /// Write a size prefixed UTF-32 string to stream
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the prefix and the code units
+    ///     `unit`       - Whether the prefix counts bytes or code units
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when the length of `value` does not fit in `PrefixT`
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_size_prefixed_utf32_string<PrefixT: PrimInt>(
+        &mut self,
+        value: &str,
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> io::Result<()> {
+        let bytes = encode_utf32(value, endianness);
+        write_length_prefix::<Self, PrefixT>(self, bytes.len() / 4, 4, unit, endianness)?;
+        self.write_all(&bytes)
+    }
+
+    // ------------------------------------------------------------------------------- Padding
+    /// Write `length` bytes of padding that repeat `fill` (EG `&[0xCD]` or `b"PADDING"`).
+    /// The last repetition is cut short when `length` is not a multiple of its length.
+    ///
+    /// # Parameters
+    ///     `length` - Number of padding bytes to write
+    ///     `fill`   - The pattern to repeat
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when `fill` is empty
+    ///     io::Error::WriteZero when the stream could not accept the full buffer
+    fn write_padding(&mut self, length: usize, fill: &[u8]) -> io::Result<()> {
+        if fill.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Fill pattern is empty."));
+        }
+
+        // Whole repetitions only, so every chunk starts at the beginning of the pattern
+        let chunk_length = fill.len() * (PADDING_CHUNK / fill.len()).max(1);
+        let chunk: Vec<u8> = fill.iter().copied().cycle().take(chunk_length.min(length)).collect();
+
+        let mut remaining = length;
+        while remaining > 0 {
+            let written = remaining.min(chunk.len());
+            self.write_all(&chunk[..written])?;
+            remaining -= written;
+        }
+
+        Ok(())
+    }
+}
+
+/// Size of the buffer `write_padding` repeats the fill pattern into
+const PADDING_CHUNK: usize = 4096;
+
+/// Writes `length` as a length prefix of any integer type from u8 to u128, taking up
+/// exactly `size_of::<PrefixT>()` bytes
+///
+/// # Errors
+///     io::Error during write
+///     io::Error::InvalidInput when `length` does not fit in `PrefixT`
+pub(crate) fn write_size_prefix<StreamT: Write + ?Sized, PrefixT: PrimInt>(
+    stream: &mut StreamT,
+    length: usize,
+    endianness: Endianness,
+) -> io::Result<()> {
+    let (buffer, width) = encode_size_prefix::<PrefixT>(length, endianness)?;
+
+    stream.write_all(&buffer[..width])
+}
+
+/// Encodes `length` as a `PrefixT` length prefix without writing it
+///
+/// # Errors
+///     io::Error::InvalidInput when `length` does not fit in `PrefixT`
+///
+/// # Returns
+///     A buffer holding the prefix in its first bytes, and the width of the prefix
+pub(crate) fn encode_size_prefix<PrefixT: PrimInt>(
+    length: usize,
+    endianness: Endianness,
+) -> io::Result<([u8; 16], usize)> {
+    let width = std::mem::size_of::<PrefixT>();
+    let value: u128 = PrefixT::from(length)
+        .and_then(|prefix| prefix.to_u128())
+        .ok_or(Error::new(
+            ErrorKind::InvalidInput,
+            "Length does not fit in the size prefix.",
+        ))?;
+
+    let mut buffer = value.to_le_bytes();
+    let prefix = buffer.get_mut(..width).ok_or(Error::new(
+        ErrorKind::InvalidInput,
+        "Size prefix types wider than 128 bits are not supported.",
+    ))?;
+    if endianness == Endianness::Big {
+        prefix.reverse();
+    }
+
+    Ok((buffer, width))
+}
+
+/// Writes `count` elements of `element_size` bytes as a length prefix in `unit`
+///
+/// # Errors
+///     io::Error during write
+///     io::Error::InvalidInput when the length does not fit in `PrefixT`
+fn write_length_prefix<StreamT: Write + ?Sized, PrefixT: PrimInt>(
+    stream: &mut StreamT,
+    count: usize,
+    element_size: usize,
+    unit: LengthUnit,
+    endianness: Endianness,
+) -> io::Result<()> {
+    let length = unit.prefix_value(count, element_size).ok_or(Error::new(
+        ErrorKind::InvalidInput,
+        "Length does not fit in the size prefix.",
+    ))?;
+
+    write_size_prefix::<StreamT, PrefixT>(stream, length, endianness)
+}
+
+/// Encodes `value` as UTF-16 code units in the requested byte order
+fn encode_utf16(value: &str, endianness: Endianness) -> Vec<u8> {
+    value
+        .encode_utf16()
+        .flat_map(|unit| match endianness {
+            Endianness::Little => unit.to_le_bytes(),
+            Endianness::Big => unit.to_be_bytes(),
+        })
+        .collect()
+}
+
+/// Encodes `value` as UTF-32 code units in the requested byte order
+fn encode_utf32(value: &str, endianness: Endianness) -> Vec<u8> {
+    value
+        .chars()
+        .flat_map(|character| match endianness {
+            Endianness::Little => u32::from(character).to_le_bytes(),
+            Endianness::Big => u32::from(character).to_be_bytes(),
+        })
+        .collect()
+}
+
+/// Split the low `width` bytes of `value` into `endianness` order
+fn unsigned_to_bytes(value: u64, width: usize, endianness: Endianness) -> Vec<u8> {
+    let bytes = value.to_le_bytes();
+    match endianness {
+        Endianness::Little => bytes[..width].to_vec(),
+        Endianness::Big => bytes[..width].iter().rev().copied().collect(),
+    }
+}
+
+fn out_of_range(bits: u32) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("Value does not fit in {bits} bits."),
+    )
+}
+
+/// Write plain data with a single write, swapping a copy of it only when needed
+fn write_plain_slice<StreamT: Write, T: PlainData>(
+    stream: &mut StreamT,
+    values: &[T],
+    endianness: Endianness,
+) -> io::Result<()> {
+    if endianness == *SYS_ENDIANNESS {
+        return stream.write_all(as_bytes(values));
+    }
+
+    let mut swapped = values.to_vec();
+    swap_slice_inplace(&mut swapped, endianness);
+
+    stream.write_all(as_bytes(&swapped))
 }
@@ -0,0 +1,311 @@
+use std::io::{self, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+/// Order bits are consumed from (or packed into) each byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The first bit is the most significant bit of the byte
+    MsbFirst,
+    /// The first bit is the least significant bit of the byte
+    LsbFirst,
+}
+
+/// Reads fields of any bit width from a byte stream.
+///
+/// Once byte aligned (see `align`) the wrapper implements `Read` (and `Seek` when
+/// the stream does), so every `BinaryReader`/`BinaryUtils` method is available on it.
+pub struct BitReader<StreamT: Read> {
+    stream: StreamT,
+    order: BitOrder,
+    current: u8,
+    remaining: u32,
+}
+
+impl<StreamT: Read> BitReader<StreamT> {
+    pub fn new(stream: StreamT, order: BitOrder) -> Self {
+        BitReader {
+            stream,
+            order,
+            current: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Read a single bit from the stream
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::UnexpectedEof if there wasn't enough data in the stream
+    pub fn read_bit(&mut self) -> io::Result<bool> {
+        if self.remaining == 0 {
+            let mut buffer: [u8; 1] = [0u8; 1];
+            self.stream.read_exact(&mut buffer)?;
+            self.current = buffer[0];
+            self.remaining = 8;
+        }
+
+        let shift = match self.order {
+            BitOrder::MsbFirst => self.remaining - 1,
+            BitOrder::LsbFirst => 8 - self.remaining,
+        };
+        self.remaining -= 1;
+
+        Ok((self.current >> shift) & 1 == 1)
+    }
+
+    /// Read an unsigned `count` bit field from the stream. With `BitOrder::MsbFirst`
+    /// the first bit read is the most significant bit of the result, with
+    /// `BitOrder::LsbFirst` it is the least significant.
+    ///
+    /// # Parameters
+    ///     `count` - Width of the field in bits (at most 64)
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::InvalidInput when `count` is larger than 64
+    ///     io::Error::UnexpectedEof if there wasn't enough data in the stream
+    pub fn read_bits(&mut self, count: u32) -> io::Result<u64> {
+        if count > u64::BITS {
+            return Err(Error::from(ErrorKind::InvalidInput));
+        }
+
+        let mut value: u64 = 0;
+        for index in 0..count {
+            let bit = self.read_bit()? as u64;
+            match self.order {
+                BitOrder::MsbFirst => value = (value << 1) | bit,
+                BitOrder::LsbFirst => value |= bit << index,
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Read a two's complement `count` bit field from the stream, sign extended to i64
+    ///
+    /// # Parameters
+    ///     `count` - Width of the field in bits (at most 64)
+    ///
+    /// # Errors
+    ///     io::Error during read
+    ///     io::Error::InvalidInput when `count` is larger than 64
+    ///     io::Error::UnexpectedEof if there wasn't enough data in the stream
+    pub fn read_signed_bits(&mut self, count: u32) -> io::Result<i64> {
+        let value = self.read_bits(count)?;
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let unused = u64::BITS - count;
+        Ok(((value << unused) as i64) >> unused)
+    }
+
+    /// Discard the rest of the current byte so the next read starts on a byte boundary
+    pub fn align(&mut self) {
+        self.remaining = 0;
+    }
+
+    /// Whether the next read starts on a byte boundary
+    pub fn is_aligned(&self) -> bool {
+        self.remaining == 0
+    }
+
+    pub fn get_ref(&self) -> &StreamT {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut StreamT {
+        &mut self.stream
+    }
+
+    /// Unwrap the stream. Any unread bits of the current byte are lost.
+    pub fn into_inner(self) -> StreamT {
+        self.stream
+    }
+}
+
+/// Byte level reads are only allowed while aligned
+impl<StreamT: Read> Read for BitReader<StreamT> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if !self.is_aligned() {
+            return Err(not_aligned());
+        }
+
+        self.stream.read(buf)
+    }
+}
+
+/// Seeking is only allowed while aligned
+impl<StreamT: Read + Seek> Seek for BitReader<StreamT> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        if !self.is_aligned() {
+            return Err(not_aligned());
+        }
+
+        self.stream.seek(position)
+    }
+}
+
+/// Writes fields of any bit width to a byte stream.
+///
+/// Bits are collected until a byte is complete. A partially filled byte is only
+/// written by `align` or `into_inner`, which pad it with zero bits. Once byte
+/// aligned the wrapper implements `Write` (and `Seek` when the stream does), so
+/// the `BinaryWriter` methods are available on it.
+pub struct BitWriter<StreamT: Write> {
+    stream: StreamT,
+    order: BitOrder,
+    current: u8,
+    filled: u32,
+}
+
+impl<StreamT: Write> BitWriter<StreamT> {
+    pub fn new(stream: StreamT, order: BitOrder) -> Self {
+        BitWriter {
+            stream,
+            order,
+            current: 0,
+            filled: 0,
+        }
+    }
+
+    /// Write a single bit to the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    pub fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+        let shift = match self.order {
+            BitOrder::MsbFirst => 7 - self.filled,
+            BitOrder::LsbFirst => self.filled,
+        };
+        self.current |= (bit as u8) << shift;
+        self.filled += 1;
+
+        if self.filled == 8 {
+            self.stream.write_all(&[self.current])?;
+            self.current = 0;
+            self.filled = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Write the low `count` bits of `value` to the stream, in the same bit order
+    /// `BitReader::read_bits` reads them back
+    ///
+    /// # Parameters
+    ///     `value` - The value to write
+    ///     `count` - Width of the field in bits (at most 64)
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when `count` is larger than 64 or `value` does not fit in `count` bits
+    pub fn write_bits(&mut self, value: u64, count: u32) -> io::Result<()> {
+        if count > u64::BITS || (count < u64::BITS && value >> count != 0) {
+            return Err(Error::from(ErrorKind::InvalidInput));
+        }
+
+        for index in 0..count {
+            let shift = match self.order {
+                BitOrder::MsbFirst => count - 1 - index,
+                BitOrder::LsbFirst => index,
+            };
+            self.write_bit((value >> shift) & 1 == 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write `value` as a two's complement `count` bit field
+    ///
+    /// # Parameters
+    ///     `value` - The value to write
+    ///     `count` - Width of the field in bits (at most 64)
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::InvalidInput when `count` is larger than 64 or `value` does not fit in `count` bits
+    pub fn write_signed_bits(&mut self, value: i64, count: u32) -> io::Result<()> {
+        if count == 0 || count > u64::BITS {
+            return match (count, value) {
+                (0, 0) => Ok(()),
+                _ => Err(Error::from(ErrorKind::InvalidInput)),
+            };
+        }
+
+        let unused = u64::BITS - count;
+        if (value << unused) >> unused != value {
+            return Err(Error::from(ErrorKind::InvalidInput));
+        }
+
+        self.write_bits((value as u64) & (u64::MAX >> unused), count)
+    }
+
+    /// Pad the current byte with zero bits and write it, so the next write starts
+    /// on a byte boundary
+    ///
+    /// # Errors
+    ///     io::Error during write
+    pub fn align(&mut self) -> io::Result<()> {
+        while !self.is_aligned() {
+            self.write_bit(false)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether the next write starts on a byte boundary
+    pub fn is_aligned(&self) -> bool {
+        self.filled == 0
+    }
+
+    pub fn get_ref(&self) -> &StreamT {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut StreamT {
+        &mut self.stream
+    }
+
+    /// Align to the next byte boundary and unwrap the stream
+    ///
+    /// # Errors
+    ///     io::Error during write
+    pub fn into_inner(mut self) -> io::Result<StreamT> {
+        self.align()?;
+
+        Ok(self.stream)
+    }
+}
+
+/// Byte level writes are only allowed while aligned
+impl<StreamT: Write> Write for BitWriter<StreamT> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.is_aligned() {
+            return Err(not_aligned());
+        }
+
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Seeking is only allowed while aligned
+impl<StreamT: Write + Seek> Seek for BitWriter<StreamT> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        if !self.is_aligned() {
+            return Err(not_aligned());
+        }
+
+        self.stream.seek(position)
+    }
+}
+
+fn not_aligned() -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        "Byte level access requires the bit stream to be byte aligned.",
+    )
+}
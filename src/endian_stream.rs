@@ -0,0 +1,218 @@
+use std::io::{self, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+use super::binary_reader::BinaryReader;
+use super::binary_traits::{BinRead, BinWrite};
+use super::binary_writer::BinaryWriter;
+use super::endian_types::ByteSwap;
+use super::endianness::Endianness;
+
+/// A stream that carries its own current `Endianness`.
+///
+/// The argument-less `read_*`/`write_*` methods use the current endianness, which can
+/// be changed at any time, overridden for a scope, or picked from a header magic. The
+/// wrapper forwards `Read`, `Write` and `Seek`, so the `BinaryReader`/`BinaryWriter`
+/// methods that take an explicit endianness are still available through their traits
+/// (e.g. `BinaryReader::read_u32(&mut stream, Endianness::Big)`).
+pub struct EndianStream<StreamT> {
+    stream: StreamT,
+    endianness: Endianness,
+}
+
+impl<StreamT> EndianStream<StreamT> {
+    pub fn new(stream: StreamT, endianness: Endianness) -> Self {
+        EndianStream { stream, endianness }
+    }
+
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    pub fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+
+    /// Run `scope` with `endianness` as the current endianness, then restore the
+    /// previous one (also when `scope` returns an error)
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness to use inside the scope
+    ///     `scope`      - Closure receiving the stream
+    ///
+    /// # Returns
+    ///     Whatever `scope` returns
+    pub fn with_endianness<ResultT>(
+        &mut self,
+        endianness: Endianness,
+        scope: impl FnOnce(&mut Self) -> ResultT,
+    ) -> ResultT {
+        let previous = std::mem::replace(&mut self.endianness, endianness);
+        let result = scope(self);
+        self.endianness = previous;
+
+        result
+    }
+
+    pub fn get_ref(&self) -> &StreamT {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut StreamT {
+        &mut self.stream
+    }
+
+    pub fn into_inner(self) -> StreamT {
+        self.stream
+    }
+}
+
+impl<StreamT: Read> EndianStream<StreamT> {
+    pub fn read_u16(&mut self) -> io::Result<u16> {
+        self.stream.read_u16(self.endianness)
+    }
+
+    pub fn read_i16(&mut self) -> io::Result<i16> {
+        self.stream.read_i16(self.endianness)
+    }
+
+    pub fn read_u32(&mut self) -> io::Result<u32> {
+        self.stream.read_u32(self.endianness)
+    }
+
+    pub fn read_i32(&mut self) -> io::Result<i32> {
+        self.stream.read_i32(self.endianness)
+    }
+
+    pub fn read_u64(&mut self) -> io::Result<u64> {
+        self.stream.read_u64(self.endianness)
+    }
+
+    pub fn read_i64(&mut self) -> io::Result<i64> {
+        self.stream.read_i64(self.endianness)
+    }
+
+    pub fn read_f32(&mut self) -> io::Result<f32> {
+        self.stream.read_f32(self.endianness)
+    }
+
+    pub fn read_f64(&mut self) -> io::Result<f64> {
+        self.stream.read_f64(self.endianness)
+    }
+}
+
+impl<StreamT: Read + Seek> EndianStream<StreamT> {
+    /// Read any `BinRead` type in the current endianness
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the type's `BinRead` implementation reports
+    pub fn read_value<T: BinRead>(&mut self) -> io::Result<T> {
+        T::read_from(&mut self.stream, self.endianness)
+    }
+
+    /// Read a magic value and switch to the endianness it was written in. The magic
+    /// reads back as `expected` in one byte order and byte swapped in the other, so
+    /// e.g. a `0xFEFF` BOM selects little endian for `FF FE` and big endian for `FE FF`.
+    ///
+    /// # Parameters
+    ///     `expected` - The magic value as the format defines it
+    ///
+    /// # Errors
+    ///     io::Error              - during read
+    ///     io::Error::InvalidData - The value read matches `expected` in neither byte order
+    ///
+    /// # Returns
+    ///     The detected endianness, which is now the current endianness
+    pub fn detect_endianness<T>(&mut self, expected: T) -> io::Result<Endianness>
+    where
+        T: BinRead + ByteSwap + PartialEq,
+    {
+        let found = T::read_from(&mut self.stream, Endianness::Little)?;
+
+        let endianness = if found == expected {
+            Endianness::Little
+        } else if found == expected.swap_bytes() {
+            Endianness::Big
+        } else {
+            return Err(Error::new(ErrorKind::InvalidData, "Magic value mismatch."));
+        };
+        self.endianness = endianness;
+
+        Ok(endianness)
+    }
+}
+
+impl<StreamT: Write> EndianStream<StreamT> {
+    pub fn write_u16(&mut self, value: u16) -> io::Result<()> {
+        self.stream.write_u16(value, self.endianness)
+    }
+
+    pub fn write_i16(&mut self, value: i16) -> io::Result<()> {
+        self.stream.write_i16(value, self.endianness)
+    }
+
+    pub fn write_u32(&mut self, value: u32) -> io::Result<()> {
+        self.stream.write_u32(value, self.endianness)
+    }
+
+    pub fn write_i32(&mut self, value: i32) -> io::Result<()> {
+        self.stream.write_i32(value, self.endianness)
+    }
+
+    pub fn write_u64(&mut self, value: u64) -> io::Result<()> {
+        self.stream.write_u64(value, self.endianness)
+    }
+
+    pub fn write_i64(&mut self, value: i64) -> io::Result<()> {
+        self.stream.write_i64(value, self.endianness)
+    }
+
+    pub fn write_f32(&mut self, value: f32) -> io::Result<()> {
+        self.stream.write_f32(value, self.endianness)
+    }
+
+    pub fn write_f64(&mut self, value: f64) -> io::Result<()> {
+        self.stream.write_f64(value, self.endianness)
+    }
+}
+
+impl<StreamT: Write> EndianStream<StreamT> {
+    /// Write any `BinWrite` type in the current endianness
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the type's `BinWrite` implementation reports
+    pub fn write_value<T: BinWrite + ?Sized>(&mut self, value: &T) -> io::Result<()> {
+        value.write_to(&mut self.stream, self.endianness)
+    }
+}
+
+impl<StreamT: Write + Seek> EndianStream<StreamT> {
+    /// Write any `BinWrite` type in the current endianness, including ones that need
+    /// to seek
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the type's `BinWrite` implementation reports
+    pub fn write_value_seekable<T: BinWrite + ?Sized>(&mut self, value: &T) -> io::Result<()> {
+        value.write_to_seekable(&mut self.stream, self.endianness)
+    }
+}
+
+impl<StreamT: Read> Read for EndianStream<StreamT> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.read(buf)
+    }
+}
+
+impl<StreamT: Write> Write for EndianStream<StreamT> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+impl<StreamT: Seek> Seek for EndianStream<StreamT> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        self.stream.seek(position)
+    }
+}
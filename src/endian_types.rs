@@ -0,0 +1,234 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read, Seek, Write};
+use std::marker::PhantomData;
+
+use half::{bf16, f16};
+
+use super::binary_traits::{BinRead, BinWrite};
+use super::endianness::{Endianness, SYS_ENDIANNESS};
+
+/// A primitive whose byte order can be reversed, usable inside `Le`, `Be` and `Ne`.
+///
+/// `Bits` is the unsigned integer with the same layout. The wrappers store it rather
+/// than `Self`, so a float with its bytes swapped is never held as a float.
+pub trait ByteSwap: Copy {
+    type Bits: ByteSwap + Default + Eq + Hash;
+
+    fn swap_bytes(self) -> Self;
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+macro_rules! impl_byte_swap_int {
+    ($($type:ty => $bits:ty),+) => {
+        $(impl ByteSwap for $type {
+            type Bits = $bits;
+
+            fn swap_bytes(self) -> Self {
+                <$type>::swap_bytes(self)
+            }
+
+            fn to_bits(self) -> $bits {
+                self as $bits
+            }
+
+            fn from_bits(bits: $bits) -> Self {
+                bits as $type
+            }
+        })+
+    };
+}
+
+macro_rules! impl_byte_swap_float {
+    ($($type:ty => $bits:ty),+) => {
+        $(impl ByteSwap for $type {
+            type Bits = $bits;
+
+            fn swap_bytes(self) -> Self {
+                <$type>::from_bits(self.to_bits().swap_bytes())
+            }
+
+            fn to_bits(self) -> $bits {
+                <$type>::to_bits(self)
+            }
+
+            fn from_bits(bits: $bits) -> Self {
+                <$type>::from_bits(bits)
+            }
+        })+
+    };
+}
+
+impl_byte_swap_int!(
+    u8 => u8, i8 => u8, u16 => u16, i16 => u16, u32 => u32, i32 => u32,
+    u64 => u64, i64 => u64, u128 => u128, i128 => u128
+);
+impl_byte_swap_float!(f16 => u16, bf16 => u16, f32 => u32, f64 => u64);
+
+/// A primitive without padding for which every bit pattern is a valid value, so a
+/// slice of it can be viewed as bytes and filled straight from a stream.
+///
+/// # Safety
+///     Only implement for types that meet the above; this is relied on by `as_bytes_mut`.
+pub(crate) unsafe trait PlainData: ByteSwap + Default {}
+
+macro_rules! impl_plain_data {
+    ($($type:ty),+) => {
+        $(unsafe impl PlainData for $type {})+
+    };
+}
+
+impl_plain_data!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f16, bf16, f32, f64);
+
+/// View a slice of plain data as its underlying bytes
+pub(crate) fn as_bytes<T: PlainData>(values: &[T]) -> &[u8] {
+    // SAFETY: PlainData types have no padding, so every byte is initialized
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast::<u8>(), size_of_val(values)) }
+}
+
+/// View a slice of plain data as its underlying bytes, for filling from a stream
+pub(crate) fn as_bytes_mut<T: PlainData>(values: &mut [T]) -> &mut [u8] {
+    // SAFETY: PlainData types have no padding and accept any bit pattern
+    unsafe {
+        std::slice::from_raw_parts_mut(values.as_mut_ptr().cast::<u8>(), size_of_val(values))
+    }
+}
+
+/// Convert every value between native order and `endianness`. Does nothing when they
+/// match; otherwise a tight loop the compiler vectorises.
+pub(crate) fn swap_slice_inplace<T: PlainData>(values: &mut [T], endianness: Endianness) {
+    if endianness != *SYS_ENDIANNESS {
+        for value in values.iter_mut() {
+            *value = value.swap_bytes();
+        }
+    }
+}
+
+/// Defines a `#[repr(transparent)]` wrapper that keeps its value in `$endianness`
+/// byte order in memory, so it can be used directly in `#[repr(C)]` file layouts.
+/// `$stored_native` is a constant telling whether that order is the host's.
+///
+/// Comparing and hashing go through the native value, so `-0.0` equals `0.0` like the
+/// float itself. Float wrappers are not `Eq` or `Hash`, as their values aren't.
+macro_rules! endian_wrapper {
+    ($(#[$doc:meta])* $name:ident, $endianness:expr, $stored_native:expr) => {
+        $(#[$doc])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default)]
+        pub struct $name<T: ByteSwap> {
+            bits: T::Bits,
+            value_type: PhantomData<T>,
+        }
+
+        impl<T: ByteSwap> $name<T> {
+            /// Wrap a native value
+            pub fn new(value: T) -> Self {
+                $name {
+                    bits: Self::convert(value.to_bits()),
+                    value_type: PhantomData,
+                }
+            }
+
+            /// The native value
+            pub fn get(self) -> T {
+                T::from_bits(Self::convert(self.bits))
+            }
+
+            /// Replace the wrapped value with a native value
+            pub fn set(&mut self, value: T) {
+                self.bits = Self::convert(value.to_bits());
+            }
+
+            /// Swaps between native and stored order, which is the same operation both ways.
+            /// The branch is on a constant and compiles away.
+            fn convert(bits: T::Bits) -> T::Bits {
+                if $stored_native {
+                    bits
+                } else {
+                    bits.swap_bytes()
+                }
+            }
+        }
+
+        impl<T: ByteSwap + PartialEq> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl<T: ByteSwap + Eq> Eq for $name<T> {}
+
+        impl<T: ByteSwap + Hash> Hash for $name<T> {
+            fn hash<HasherT: Hasher>(&self, state: &mut HasherT) {
+                self.get().hash(state);
+            }
+        }
+
+        impl<T: ByteSwap> From<T> for $name<T> {
+            fn from(value: T) -> Self {
+                $name::new(value)
+            }
+        }
+
+        impl<T: ByteSwap + fmt::Debug> fmt::Debug for $name<T> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter
+                    .debug_tuple(stringify!($name))
+                    .field(&self.get())
+                    .finish()
+            }
+        }
+
+        /// Reads in the wrapper's own byte order, ignoring the endianness passed in
+        impl<T: ByteSwap + BinRead> BinRead for $name<T> {
+            fn read_from<StreamT: Read + Seek>(
+                stream: &mut StreamT,
+                _: Endianness,
+            ) -> io::Result<Self> {
+                Ok($name::new(T::read_from(stream, $endianness)?))
+            }
+        }
+
+        /// Writes in the wrapper's own byte order, ignoring the endianness passed in
+        impl<T: ByteSwap + BinWrite> BinWrite for $name<T> {
+            fn write_to<StreamT: Write>(
+                &self,
+                stream: &mut StreamT,
+                _: Endianness,
+            ) -> io::Result<()> {
+                self.get().write_to(stream, $endianness)
+            }
+        }
+    };
+}
+
+endian_wrapper!(
+    /// A little endian `T`.
+    ///
+    /// The value is stored in little endian byte order, so the in memory layout
+    /// matches the file and `get`/`new` only swap bytes on big endian hosts.
+    Le,
+    Endianness::Little,
+    cfg!(target_endian = "little")
+);
+
+endian_wrapper!(
+    /// A big endian `T`.
+    ///
+    /// The value is stored in big endian byte order, so the in memory layout
+    /// matches the file and `get`/`new` only swap bytes on little endian hosts.
+    Be,
+    Endianness::Big,
+    cfg!(target_endian = "big")
+);
+
+endian_wrapper!(
+    /// A native endian `T`.
+    ///
+    /// Mostly useful for documenting that a layout follows the host, e.g. in formats
+    /// that are only ever written and read back on the same machine.
+    Ne,
+    *SYS_ENDIANNESS,
+    true
+);
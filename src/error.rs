@@ -0,0 +1,206 @@
+use std::fmt::{self, Debug, Display};
+use std::io::{self, ErrorKind};
+
+/// One step of the breadcrumb path leading to a failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A named field or context, e.g. `header`
+    Field(String),
+    /// An element of a sequence, e.g. `[12]`
+    Index(usize),
+}
+
+impl From<&str> for PathSegment {
+    fn from(name: &str) -> Self {
+        PathSegment::Field(name.to_owned())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(name: String) -> Self {
+        PathSegment::Field(name)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(index: usize) -> Self {
+        PathSegment::Index(index)
+    }
+}
+
+/// A parsing error that knows where it happened.
+///
+/// On top of the `io::ErrorKind` and message it records the offset of the failure
+/// (where the stream stood when it surfaced, or the start of a value that failed
+/// validation), the path of named contexts leading to it (`header.entries[12].name`)
+/// and, for validation failures, the expected and found values.
+///
+/// The `BinRead`/`BinWrite` traits still return `io::Error`. A `binre::Error` travels
+/// through them wrapped inside an `io::Error` of the same kind and is recovered by
+/// `binre::Error::from`, so context added at any level is preserved.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+    offset: Option<u64>,
+    path: Vec<PathSegment>,
+    expected: Option<String>,
+    found: Option<String>,
+    source: Option<io::Error>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Error {
+            kind,
+            message: message.into(),
+            offset: None,
+            path: Vec::new(),
+            expected: None,
+            found: None,
+            source: None,
+        }
+    }
+
+    /// An `ErrorKind::InvalidData` validation failure, e.g. a wrong magic value
+    ///
+    /// # Parameters
+    ///     `message`  - What was being validated
+    ///     `expected` - The value the format requires
+    ///     `found`    - The value in the stream
+    pub fn mismatch(message: impl Into<String>, expected: impl Debug, found: impl Debug) -> Self {
+        Error {
+            expected: Some(format!("{expected:?}")),
+            found: Some(format!("{found:?}")),
+            ..Error::new(ErrorKind::InvalidData, message)
+        }
+    }
+
+    /// Record where the failure happened. The first (innermost) offset is kept, as it
+    /// is the most precise one.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+
+    /// Prepend a context to the path. Contexts are added from the inside out as the
+    /// error travels up.
+    pub fn with_context(mut self, segment: impl Into<PathSegment>) -> Self {
+        self.path.insert(0, segment.into());
+        self
+    }
+
+    /// Adds `segment` to the path of a failed result. This is what
+    /// `BinaryUtils::with_context` does for streams that can't seek, so no offset is
+    /// recorded.
+    ///
+    /// # Parameters
+    ///     `result`  - The outcome of the step
+    ///     `segment` - Field name or element index describing the step
+    pub fn add_context<T>(result: io::Result<T>, segment: impl Into<PathSegment>) -> io::Result<T> {
+        result.map_err(|error| Error::from(error).with_context(segment).into())
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    pub fn found(&self) -> Option<&str> {
+        self.found.as_deref()
+    }
+
+    /// The path formatted as `header.entries[12].name`
+    pub fn path_string(&self) -> String {
+        let mut result = String::new();
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(name) if result.is_empty() => result.push_str(name),
+                PathSegment::Field(name) => {
+                    result.push('.');
+                    result.push_str(name);
+                }
+                PathSegment::Index(index) => result.push_str(&format!("[{index}]")),
+            }
+        }
+
+        result
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.path.is_empty(), self.offset) {
+            (true, None) => {}
+            (true, Some(offset)) => write!(formatter, "at offset 0x{offset:X}: ")?,
+            (false, None) => write!(formatter, "{}: ", self.path_string())?,
+            (false, Some(offset)) => {
+                write!(formatter, "{} at offset 0x{offset:X}: ", self.path_string())?
+            }
+        }
+        write!(formatter, "{}", self.message)?;
+
+        if let (Some(expected), Some(found)) = (&self.expected, &self.found) {
+            write!(formatter, " Expected {expected}, found {found}.")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {
+    /// The plain `io::Error` this error was created from, with its raw OS error
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Unwraps a `binre::Error` carried inside the `io::Error`, or wraps a plain one,
+/// keeping it as the source
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        if !error.get_ref().is_some_and(|inner| inner.is::<Error>()) {
+            let plain = Error::new(error.kind(), error.to_string());
+            return Error {
+                source: Some(error),
+                ..plain
+            };
+        }
+
+        match error.into_inner().map(|inner| inner.downcast::<Error>()) {
+            Some(Ok(inner)) => *inner,
+            _ => unreachable!("the inner error was checked to be a binre::Error"),
+        }
+    }
+}
+
+/// Carries the error inside an `io::Error` of the same kind. A wrapped plain
+/// `io::Error` that gained no context is handed back as it was.
+impl From<Error> for io::Error {
+    fn from(mut error: Error) -> Self {
+        if error.offset.is_none() && error.path.is_empty() {
+            if let Some(source) = error.source.take() {
+                return source;
+            }
+        }
+
+        io::Error::new(error.kind, error)
+    }
+}
@@ -0,0 +1,84 @@
+use std::io::{self, Error, ErrorKind, Read, Seek, Write};
+
+use num_traits::PrimInt;
+
+use super::binary_traits::{BinRead, BinWrite};
+use super::binary_utils::BinaryUtils;
+use super::endianness::Endianness;
+
+/// An `OffsetT` sized file offset along with the `T` it points to.
+///
+/// Reading a `FilePtr` reads the offset, follows it to parse `T` and then returns
+/// to the position right after the offset. A zero offset is a null pointer and
+/// leaves `value` as `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FilePtr<OffsetT, T> {
+    pub offset: OffsetT,
+    pub value: Option<T>,
+}
+
+impl<OffsetT, T> FilePtr<OffsetT, T>
+where
+    OffsetT: PrimInt + BinRead,
+    T: BinRead,
+{
+    /// Read an offset relative to `base` and the value it points to
+    ///
+    /// # Parameters
+    ///     `stream`     - The stream to read from
+    ///     `base`       - Position the offset is relative to
+    ///     `endianness` - Endianness of both the offset and the value
+    ///
+    /// # Errors
+    ///     io::Error              - Error bubbled up from stream read
+    ///     io::Error::InvalidData - The offset is negative or points past the end of the stream
+    ///
+    /// # Returns
+    ///     FilePtr holding the offset and the value, if the offset was not null
+    pub fn read_relative<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        base: u64,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        let offset = OffsetT::read_from(stream, endianness)?;
+        let absolute = offset.to_u64().ok_or(Error::new(
+            ErrorKind::InvalidData,
+            "Offset is out of range of the stream.",
+        ))?;
+
+        let value =
+            stream.read_at_offset(absolute, base, |stream| T::read_from(stream, endianness))?;
+
+        Ok(FilePtr { offset, value })
+    }
+}
+
+/// Reads an absolute offset. Use `FilePtr::read_relative` for offsets relative to
+/// something other than the start of the stream.
+impl<OffsetT, T> BinRead for FilePtr<OffsetT, T>
+where
+    OffsetT: PrimInt + BinRead,
+    T: BinRead,
+{
+    fn read_from<StreamT: Read + Seek>(
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<Self> {
+        Self::read_relative(stream, 0, endianness)
+    }
+}
+
+/// Only the offset is written; the value lives elsewhere in the file and has to be
+/// written separately.
+impl<OffsetT, T> BinWrite for FilePtr<OffsetT, T>
+where
+    OffsetT: BinWrite,
+{
+    fn write_to<StreamT: Write>(
+        &self,
+        stream: &mut StreamT,
+        endianness: Endianness,
+    ) -> io::Result<()> {
+        self.offset.write_to(stream, endianness)
+    }
+}
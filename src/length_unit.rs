@@ -0,0 +1,33 @@
+/// What the value of a length prefix counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// The prefix is the size of the data in bytes
+    Bytes,
+    /// The prefix is the number of elements (code units for strings)
+    Elements,
+}
+
+impl LengthUnit {
+    /// Converts a length prefix into an element count for elements of `element_size` bytes
+    ///
+    /// # Returns
+    ///     None when a byte length is not a multiple of `element_size`
+    pub(crate) fn element_count(self, length: usize, element_size: usize) -> Option<usize> {
+        match self {
+            LengthUnit::Elements => Some(length),
+            LengthUnit::Bytes if length.is_multiple_of(element_size) => Some(length / element_size),
+            LengthUnit::Bytes => None,
+        }
+    }
+
+    /// Converts an element count into the value stored in a length prefix
+    ///
+    /// # Returns
+    ///     None when the byte length overflows usize
+    pub(crate) fn prefix_value(self, count: usize, element_size: usize) -> Option<usize> {
+        match self {
+            LengthUnit::Elements => Some(count),
+            LengthUnit::Bytes => count.checked_mul(element_size),
+        }
+    }
+}
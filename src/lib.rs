@@ -1,20 +1,68 @@
+// The original reader code predates some of these lints; keep its style as is
+#![allow(
+    clippy::needless_return,
+    clippy::needless_question_mark,
+    clippy::seek_from_current,
+    clippy::redundant_closure
+)]
+
+// Lets the derive macros refer to `::binre` from inside this crate as well
+extern crate self as binre;
+
+mod base_stream;
 mod binary_reader;
+mod bit_stream;
+mod binary_traits;
 mod binary_utils;
 mod binary_writer;
+mod endian_stream;
+mod endian_types;
 mod endianness;
+mod error;
+mod file_ptr;
+mod length_unit;
+mod placeholder;
+mod read_limits;
+mod rollback_guard;
+mod seekable_writer;
+mod slice_reader;
+mod sub_stream;
+mod text_encoding;
 
+pub use base_stream::BaseStream;
 pub use binary_reader::BinaryReader;
+pub use bit_stream::{BitOrder, BitReader, BitWriter};
+pub use binary_traits::{BinRead, BinWrite};
 pub use binary_utils::BinaryUtils;
 pub use binary_writer::BinaryWriter;
+pub use binre_derive::{BinRead, BinWrite};
+pub use endian_stream::EndianStream;
+pub use endian_types::{Be, ByteSwap, Le, Ne};
 pub use endianness::Endianness;
+pub use error::{Error, PathSegment};
+pub use file_ptr::FilePtr;
+pub use half::{bf16, f16};
+pub use length_unit::LengthUnit;
+pub use placeholder::Placeholder;
+pub use read_limits::{LimitedReader, ReadLimits};
+pub use rollback_guard::RollbackGuard;
+pub use seekable_writer::SeekableWriter;
+pub use slice_reader::SliceReader;
+pub use sub_stream::SubStream;
+pub use text_encoding::Encoding;
 
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::{self, BufReader}, sync::Arc};
+    use std::{fs::File, io::{self, BufReader, Cursor, Seek, Write}, sync::Arc};
 
-    use crate::{BinaryReader, BinaryUtils, Endianness};
+    use crate::{
+        BinRead, BinWrite, BinaryReader, BitOrder, BitReader, BitWriter, BinaryUtils, BinaryWriter, Endianness, FilePtr,
+        Encoding, LengthUnit, bf16, f16, Be, Le, Ne, EndianStream, Error, PathSegment,
+        SliceReader, LimitedReader, ReadLimits, SeekableWriter, BaseStream, SubStream,
+    };
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn it_works() -> std::io::Result<()> {
         const EXPECTED_HEADER: [u8; 4] = [0x42, 0x49, 0x4E, 0x00];
 
@@ -54,4 +102,1045 @@ mod tests {
 
         Ok(())
     }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[binre(repr = u8)]
+    enum Shape {
+        Point,
+        Circle { radius: f32 },
+        #[binre(tag = 0x10)]
+        Rect(u16, #[binre(big)] u16),
+    }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[binre(little)]
+    struct Scene {
+        version: u32,
+        first: Shape,
+        second: Shape,
+        third: Shape,
+    }
+
+    #[test]
+    fn derive_round_trip() -> std::io::Result<()> {
+        let scene = Scene {
+            version: 2,
+            first: Shape::Point,
+            second: Shape::Circle { radius: 1.5 },
+            third: Shape::Rect(0x0102, 0x0304),
+        };
+
+        // Container endianness wins over the endianness passed in
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        scene.write_to(&mut stream, Endianness::Big)?;
+        assert_eq!(
+            stream.get_ref().as_slice(),
+            [2, 0, 0, 0, 0, 1, 0, 0, 0xC0, 0x3F, 0x10, 0x02, 0x01, 0x03, 0x04]
+        );
+
+        stream.set_position(0);
+        assert_eq!(Scene::read_from(&mut stream, Endianness::Big)?, scene);
+
+        stream.get_mut()[4] = 0x7F;
+        stream.set_position(0);
+        let error = Scene::read_from(&mut stream, Endianness::Little).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    #[derive(BinRead, BinWrite)]
+    #[binre(little)]
+    struct BinUri {
+        size: u16,
+        #[binre(count = size)]
+        data: String,
+    }
+
+    #[derive(BinRead, BinWrite)]
+    #[binre(little)]
+    struct FileData {
+        #[binre(magic = b"BIN\0")]
+        uri_count: u16,
+        #[binre(align = 4)]
+        uri_offset: u32,
+        mime_offset: u32,
+        #[binre(count = uri_count)]
+        uris: Vec<BinUri>,
+        #[binre(count = uri_count)]
+        mime_types: Vec<String>,
+        #[binre(if = uri_count > 3)]
+        extra: Option<u32>,
+    }
+
+    #[test]
+    fn derive_field_attributes() -> std::io::Result<()> {
+        let bytes = std::fs::read("./file.bin")?;
+        let mut stream = Cursor::new(bytes.as_slice());
+
+        let data = FileData::read_from(&mut stream, Endianness::Little)?;
+        assert_eq!((data.uri_offset, data.mime_offset), (0x10, 0x48));
+        assert_eq!(data.uris[1].data, "../audio/musc.mp3");
+        assert_eq!(data.mime_types, ["image/png", "audio/mpeg", "image/png"]);
+        assert!(data.extra.is_none());
+        let end = stream.position() as usize;
+
+        let mut output = Cursor::new(Vec::<u8>::new());
+        data.write_to_seekable(&mut output, Endianness::Little)?;
+        assert_eq!(output.get_ref().as_slice(), &bytes[..end]);
+
+        // `extra` would not be read back, as its condition is false
+        let mut data = data;
+        data.extra = Some(7);
+        let error = data.write_to_seekable(&mut Cursor::new(Vec::<u8>::new()), Endianness::Little);
+        let error = Error::from(error.unwrap_err());
+        assert_eq!((error.kind(), error.path_string()), (io::ErrorKind::InvalidInput, "extra".to_owned()));
+
+        let mut corrupt = bytes.clone();
+        corrupt[0] = b'X';
+        let error = FileData::read_from(&mut Cursor::new(corrupt), Endianness::Little);
+        assert_eq!(error.err().map(|e| e.kind()), Some(io::ErrorKind::InvalidData));
+
+        // The size field has to agree with the data it counts
+        let uri = BinUri { size: 4, data: "abc".to_string() };
+        let error = uri.write_to(&mut Cursor::new(Vec::<u8>::new()), Endianness::Little);
+        assert_eq!(error.err().map(|e| e.kind()), Some(io::ErrorKind::InvalidInput));
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_ptr_follows_offsets() -> std::io::Result<()> {
+        let bytes = std::fs::read("./file.bin")?;
+        let mut stream = Cursor::new(bytes.as_slice());
+
+        stream.seek(io::SeekFrom::Start(0xC))?;
+        let mime: FilePtr<u32, String> = FilePtr::read_from(&mut stream, Endianness::Little)?;
+        assert_eq!(mime.offset, 0x48);
+        assert_eq!(mime.value.as_deref(), Some("image/png"));
+        assert_eq!(stream.tell()?, 0x10);
+
+        // Skipping the u16 size prefix of the first URI
+        stream.seek(io::SeekFrom::Start(0x8))?;
+        let uri: FilePtr<u16, u8> = FilePtr::read_relative(&mut stream, 0x2, Endianness::Little)?;
+        assert_eq!(uri.value, Some(b'.'));
+
+        // Null offsets are `None`, out of range offsets are errors
+        stream.seek(io::SeekFrom::Start(0x6))?;
+        let null: FilePtr<u16, u32> = FilePtr::read_from(&mut stream, Endianness::Little)?;
+        assert_eq!(null.value, None);
+        let error = stream.read_at_offset(0x1000, 0, |s| s.read_u8()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    #[test]
+    fn placeholders_back_patch() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+
+        stream.write_string("BIN\0")?;
+        let table_offset = stream.reserve_u32(Endianness::Little)?;
+        let chunk_size = stream.reserve_u16(Endianness::Big)?;
+
+        let chunk_start = stream.tell()?;
+        stream.write_nt_string("chunk")?;
+        stream.patch_size(&chunk_size, chunk_start)?;
+
+        stream.patch_offset(&table_offset, 0)?;
+        stream.write_le_u32(0xDEADBEEF)?;
+
+        assert_eq!(
+            stream.get_ref().as_slice(),
+            b"BIN\0\x10\0\0\0\0\x06chunk\0\xEF\xBE\xAD\xDE"
+        );
+
+        let error = stream.patch(&chunk_size, 0x10000).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+
+    #[test]
+    fn size_prefixed_round_trip() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_size_prefixed_string::<u16>("../images/image.png", Endianness::Little)?;
+        stream.write_size_prefixed_bytes::<u32>(&[1, 2, 3], Endianness::Big)?;
+
+        let bytes = std::fs::read("./file.bin")?;
+        assert_eq!(&stream.get_ref()[..21], &bytes[0x10..0x25]);
+
+        stream.set_position(0);
+        assert_eq!(
+            stream.read_size_prefixed_string::<u16>(Endianness::Little)?,
+            "../images/image.png"
+        );
+        assert_eq!(*stream.read_size_prefixed_bytes::<u32>(Endianness::Big)?, [1, 2, 3]);
+
+        let error = stream
+            .write_size_prefixed_bytes::<u8>(&[0u8; 256], Endianness::Little)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wide_strings() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_nt_utf16_string("名前", Endianness::Big)?;
+        stream.write_size_prefixed_utf16_string::<u8>("𝄞a", Endianness::Little, LengthUnit::Bytes)?;
+        stream.write_size_prefixed_utf32_string::<u16>("é", Endianness::Big, LengthUnit::Elements)?;
+        stream.write_all(&[0xFF, 0xFE, b'h', 0, b'i', 0])?;
+
+        assert_eq!(&stream.get_ref()[..6], [0x54, 0x0D, 0x52, 0x4D, 0, 0]);
+
+        stream.set_position(0);
+        assert_eq!(stream.read_null_terminated_utf16_string(Endianness::Big, false)?, "名前");
+        assert_eq!(
+            stream.read_size_prefixed_utf16_string::<u8>(Endianness::Little, LengthUnit::Bytes, false)?,
+            "𝄞a"
+        );
+        assert_eq!(
+            stream.read_size_prefixed_utf32_string::<u16>(Endianness::Big, LengthUnit::Elements, false)?,
+            "é"
+        );
+        // A little endian byte order mark overrides the big endian default
+        assert_eq!(stream.read_fixed_size_utf16_string(3, Endianness::Big, true)?, "hi");
+
+        // Unpaired surrogate
+        stream.get_mut().splice(0..2, [0x00, 0xD8]);
+        stream.set_position(0);
+        let error = stream.read_fixed_size_utf16_string(1, Endianness::Little, false).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_encodings() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_nt_encoded_string("ゲーム", Encoding::ShiftJis)?;
+        stream.write_size_prefixed_encoded_string::<u8>("café €", Endianness::Little, Encoding::Windows1252)?;
+        stream.write_encoded_string("한글", Encoding::EucKr)?;
+        stream.write_encoded_string("ÿ", Encoding::Latin1)?;
+
+        stream.set_position(0);
+        assert_eq!(stream.read_null_terminated_encoded_string(Encoding::ShiftJis, false)?, "ゲーム");
+        assert_eq!(
+            stream.read_size_prefixed_encoded_string::<u8>(Endianness::Little, Encoding::Windows1252, false)?,
+            "café €"
+        );
+        assert_eq!(stream.read_fixed_size_encoded_string(4, Encoding::EucKr, false)?, "한글");
+        assert_eq!(stream.read_fixed_size_encoded_string(1, Encoding::Latin1, false)?, "ÿ");
+
+        // A lone Shift-JIS lead byte is only accepted in lossy mode
+        let mut stream = Cursor::new(vec![b'a', 0x82]);
+        let error = stream.read_fixed_size_encoded_string(2, Encoding::ShiftJis, false).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        stream.set_position(0);
+        assert_eq!(stream.read_fixed_size_encoded_string(2, Encoding::ShiftJis, true)?, "a\u{FFFD}");
+
+        let error = stream.write_encoded_string("€", Encoding::Latin1).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        let error = stream.write_encoded_string("한", Encoding::ShiftJis).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bit_streams() -> std::io::Result<()> {
+        for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+            let mut writer = BitWriter::new(Cursor::new(Vec::<u8>::new()), order);
+            writer.write_bits(0b101, 3)?;
+            writer.write_signed_bits(-300, 11)?;
+            writer.write_bits(u64::MAX, 64)?;
+            writer.align()?;
+            writer.write_le_u16(0xBEEF)?;
+            assert_eq!(writer.write_bits(8, 3).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+            let mut stream = writer.into_inner()?;
+            stream.set_position(0);
+
+            let mut reader = BitReader::new(stream, order);
+            assert_eq!(reader.read_bits(3)?, 0b101);
+            assert_eq!(reader.read_signed_bits(11)?, -300);
+            assert!(reader.read_u8().is_err());
+            assert_eq!(reader.read_bits(64)?, u64::MAX);
+            reader.align();
+            assert_eq!(reader.read_le_u16()?, 0xBEEF);
+        }
+
+        let mut msb = BitReader::new(Cursor::new([0b1100_0001u8]), BitOrder::MsbFirst);
+        assert_eq!(msb.read_bits(3)?, 0b110);
+        let mut lsb = BitReader::new(Cursor::new([0b1100_0001u8]), BitOrder::LsbFirst);
+        assert_eq!(lsb.read_bits(3)?, 0b001);
+
+        Ok(())
+    }
+
+    #[test]
+    fn variable_length_integers() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_uleb128(624485)?;
+        stream.write_sleb128(-123456)?;
+        stream.write_zigzag_varint(-2)?;
+        stream.write_vlq(0x0FFFFFFF)?;
+        stream.write_7bit_encoded_int(-1)?;
+        stream.write_uleb128(u64::MAX)?;
+        stream.write_sleb128(i64::MIN)?;
+
+        assert_eq!(
+            &stream.get_ref()[..14],
+            [0xE5, 0x8E, 0x26, 0xC0, 0xBB, 0x78, 0x03, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF]
+        );
+
+        stream.set_position(0);
+        assert_eq!(stream.read_uleb128(10)?, 624485);
+        assert_eq!(stream.read_sleb128(10)?, -123456);
+        assert_eq!(stream.read_zigzag_varint(10)?, -2);
+        assert_eq!(stream.read_vlq(4)?, 0x0FFFFFFF);
+        assert_eq!(stream.read_7bit_encoded_int(5)?, -1);
+        assert_eq!(stream.read_uleb128(10)?, u64::MAX);
+        assert_eq!(stream.read_sleb128(10)?, i64::MIN);
+
+        // Endless continuation bits fail instead of looping
+        let mut stream = Cursor::new(vec![0xFFu8; 64]);
+        assert_eq!(stream.read_uleb128(5).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        stream.set_position(0);
+        assert_eq!(stream.read_uleb128(64).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // Bits of the 10th byte beyond bit 63 do not fit
+        let mut bytes = vec![0x80u8; 9];
+        bytes.push(0x02);
+        assert!(Cursor::new(&bytes).read_uleb128(10).is_err());
+        assert!(Cursor::new(&bytes).read_sleb128(10).is_err());
+
+        // Long zero padding does not overflow the shift
+        let mut bytes = vec![0x80u8; 1000];
+        bytes.push(0x00);
+        assert_eq!(Cursor::new(&bytes).read_sleb128(usize::MAX)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn half_and_fixed_point() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_le_f16(f16::from_f32(1.5))?;
+        stream.write_be_bf16(bf16::from_f32(-2.0))?;
+        stream.write_be_fixed_point::<i32>(1.25, 16)?;
+        stream.write_le_fixed_point::<i16>(-0.5, 15)?;
+
+        assert_eq!(
+            stream.get_ref().as_slice(),
+            [0x00, 0x3E, 0xC0, 0x00, 0x00, 0x01, 0x40, 0x00, 0x00, 0xC0]
+        );
+        assert_eq!(
+            stream.write_le_fixed_point::<i16>(1.0, 15).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+
+        stream.set_position(0);
+        assert_eq!(stream.read_le_f16()?, f16::from_f32(1.5));
+        assert_eq!(stream.read_be_bf16()?.to_f32(), -2.0);
+        assert_eq!(stream.read_be_fixed_point::<i32>(16)?, 1.25);
+
+        let mut value = 0.0;
+        stream.read_into_le_fixed_point::<i16>(&mut value, 15)?;
+        assert_eq!(value, -0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn odd_width_integers() -> std::io::Result<()> {
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_le_u24(0x123456)?;
+        stream.write_be_i24(-2)?;
+        stream.write_be_u48(0x0102_0304_0506)?;
+        stream.write_le_i48(-1)?;
+        stream.write_be_u128(u128::MAX - 1)?;
+        stream.write_le_i128(i128::MIN)?;
+
+        assert_eq!(&stream.get_ref()[..12], [0x56, 0x34, 0x12, 0xFF, 0xFF, 0xFE, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(stream.write_le_u24(0x0100_0000).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(stream.write_le_i24(0x80_0000).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(stream.write_le_i48(-(1 << 47) - 1).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+        stream.set_position(0);
+        assert_eq!(stream.read_le_u24()?, 0x123456);
+        assert_eq!(stream.read_be_i24()?, -2);
+        assert_eq!(stream.read_be_u48()?, 0x0102_0304_0506);
+        assert_eq!(stream.read_le_i48()?, -1);
+        assert_eq!(stream.read_be_u128()?, u128::MAX - 1);
+        assert_eq!(i128::read_from(&mut stream, Endianness::Little)?, i128::MIN);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generic_values() -> std::io::Result<()> {
+        // A hand written implementation composes with the built in ones
+        #[derive(Debug, PartialEq)]
+        struct Rgb(u8, u8, u8);
+
+        impl BinRead for Rgb {
+            fn read_from<StreamT: io::Read + Seek>(stream: &mut StreamT, _: Endianness) -> io::Result<Self> {
+                let [r, g, b] = stream.read_value::<[u8; 3]>(Endianness::Little)?;
+                Ok(Rgb(r, g, b))
+            }
+        }
+
+        impl BinWrite for Rgb {
+            fn write_to<StreamT: Write>(&self, stream: &mut StreamT, _: Endianness) -> io::Result<()> {
+                stream.write_value(&[self.0, self.1, self.2], Endianness::Little)
+            }
+        }
+
+        let palette = vec![(1u16, Rgb(255, 0, 0)), (2u16, Rgb(0, 0, 255))];
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_value(&(true, [1u32, 2u32]), Endianness::Big)?;
+        stream.write_value(&palette, Endianness::Big)?;
+
+        assert_eq!(
+            stream.get_ref().as_slice(),
+            [1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 1, 255, 0, 0, 0, 2, 0, 0, 255]
+        );
+
+        stream.set_position(0);
+        assert_eq!(stream.read_value::<(bool, [u32; 2])>(Endianness::Big)?, (true, [1, 2]));
+        let position = stream.position();
+        assert_eq!(stream.read_vec::<(u16, Rgb)>(2, Endianness::Big)?, palette);
+
+        // A bare Vec reads to the end of the stream
+        stream.set_position(position);
+        assert_eq!(stream.read_value::<Vec<(u16, Rgb)>>(Endianness::Big)?, palette);
+
+        // Elements that read nothing would never reach the end
+        stream.set_position(0);
+        let error = stream.read_value::<Vec<[u8; 0]>>(Endianness::Big).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // Tuples name the failing element like arrays do
+        let mut stream = Cursor::new([0u8; 5]);
+        let error = crate::Error::from(stream.read_value::<(u16, [u8; 4])>(Endianness::Big).unwrap_err());
+        assert_eq!((error.path_string(), error.offset()), ("[1][3]".to_owned(), Some(5)));
+
+        // Elements read before a failure are dropped with the partial array
+        let mut stream = Cursor::new(b"a\0b\0".to_vec());
+        assert!(stream.read_value::<[String; 3]>(Endianness::Big).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn endian_wrappers() -> std::io::Result<()> {
+        #[repr(C)]
+        #[derive(BinRead, BinWrite, Debug, PartialEq)]
+        struct Header {
+            magic: Be<u32>,
+            size: Le<u32>,
+            scale: Be<f32>,
+            flags: Ne<u16>,
+        }
+
+        let header = Header {
+            magic: Be::new(0x42494E00),
+            size: 0x20.into(),
+            scale: Be::new(0.5),
+            flags: Ne::new(7),
+        };
+
+        // The wrappers hold their file layout in memory
+        let raw: [u8; 4] = unsafe { std::mem::transmute(header.magic) };
+        assert_eq!(raw, [0x42, 0x49, 0x4E, 0x00]);
+        assert_eq!(std::mem::size_of::<Header>(), 14 + 2);
+
+        // The endianness passed in is ignored
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        header.write_to(&mut stream, Endianness::Little)?;
+        assert_eq!(&stream.get_ref()[..12], [0x42, 0x49, 0x4E, 0x00, 0x20, 0, 0, 0, 0x3F, 0, 0, 0]);
+
+        stream.set_position(0);
+        let read = Header::read_from(&mut stream, Endianness::Big)?;
+        assert_eq!(read.size.get(), 0x20);
+        assert_eq!(read, header);
+
+        // Layout hints with arguments next to `C` don't get in the way
+        #[repr(C, align(8))]
+        #[derive(BinRead, BinWrite, Debug, PartialEq)]
+        struct Aligned {
+            id: Le<u16>,
+        }
+
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        Aligned { id: Le::new(0x0102) }.write_to(&mut stream, Endianness::Big)?;
+        assert_eq!(stream.get_ref().as_slice(), [0x02, 0x01]);
+        assert_eq!(std::mem::align_of::<Aligned>(), 8);
+        stream.set_position(0);
+        assert_eq!(Aligned::read_from(&mut stream, Endianness::Big)?.id.get(), 0x0102);
+
+        // Floats compare by value and keep their exact bits, NaN payloads included
+        assert_eq!(Be::new(0.0f32), Be::new(-0.0f32));
+        assert_ne!(Le::new(f32::NAN), Le::new(f32::NAN));
+        let signaling = f32::from_bits(0x7F800001);
+        assert_eq!(Be::new(signaling).get().to_bits(), 0x7F800001);
+        assert_eq!(Le::new(signaling).get().to_bits(), 0x7F800001);
+
+        Ok(())
+    }
+
+    #[test]
+    fn endian_stream_context() -> std::io::Result<()> {
+        let mut writer = EndianStream::new(Cursor::new(Vec::<u8>::new()), Endianness::Big);
+        writer.write_value(&0xFEFFu16)?;
+        writer.write_u32(0x01020304)?;
+        writer.with_endianness(Endianness::Little, |writer| writer.write_u16(0x0506))?;
+        writer.write_value(&[7u16, 8u16])?;
+
+        let data = writer.into_inner().into_inner();
+        assert_eq!(data, [0xFE, 0xFF, 1, 2, 3, 4, 6, 5, 0, 7, 0, 8]);
+
+        let mut reader = EndianStream::new(Cursor::new(data), Endianness::Little);
+        assert_eq!(reader.detect_endianness(0xFEFFu16)?, Endianness::Big);
+        assert_eq!(reader.read_u32()?, 0x01020304);
+        let value = reader.with_endianness(Endianness::Little, |reader| reader.read_u16())?;
+        assert_eq!(value, 0x0506);
+        assert_eq!(reader.endianness(), Endianness::Big);
+        assert_eq!(reader.read_value::<[u16; 2]>()?, [7, 8]);
+
+        // The explicit endianness methods are still there through the traits
+        reader.get_mut().set_position(0);
+        assert_eq!(BinaryReader::read_u16(&mut reader, Endianness::Little)?, 0xFFFE);
+        reader.get_mut().set_position(0);
+        assert!(reader.detect_endianness(0x1234u16).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_context() -> std::io::Result<()> {
+        #[derive(BinRead, BinWrite, Debug)]
+        struct Entry {
+            id: u16,
+            #[binre(magic = b"NM")]
+            name: String,
+        }
+
+        #[derive(BinRead, BinWrite, Debug)]
+        struct Header {
+            count: u8,
+            #[binre(count = count)]
+            entries: Vec<Entry>,
+        }
+
+        #[derive(BinRead, BinWrite, Debug)]
+        struct Archive {
+            header: Header,
+        }
+
+        // The second entry's magic is broken
+        let data: Vec<u8> = vec![2, 1, 0, b'N', b'M', b'a', 0, 2, 0, b'N', b'X', b'b', 0];
+        let error = Error::from(Archive::read_from(&mut Cursor::new(&data), Endianness::Little).unwrap_err());
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.path_string(), "header.entries[1].name");
+        assert_eq!(
+            error.path(),
+            [PathSegment::from("header"), PathSegment::from("entries"), PathSegment::Index(1), PathSegment::from("name")]
+        );
+        assert_eq!(error.offset(), Some(9));
+        assert_eq!(error.expected(), Some("[78, 77]"));
+        assert_eq!(error.found(), Some("[78, 88]"));
+        assert_eq!(
+            error.to_string(),
+            "header.entries[1].name at offset 0x9: Magic value mismatch. Expected [78, 77], found [78, 88]."
+        );
+
+        // Running out of data keeps the original kind and the io::Error as the source,
+        // and is located where the data ran out
+        let error = Error::from(Archive::read_from(&mut Cursor::new(&data[..4]), Endianness::Little).unwrap_err());
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(error.path_string(), "header.entries[0].name");
+        assert_eq!(error.offset(), Some(4));
+        let source = std::error::Error::source(&error).and_then(|source| source.downcast_ref::<io::Error>());
+        assert_eq!(source.map(io::Error::kind), Some(io::ErrorKind::UnexpectedEof));
+
+        // Without added context the original io::Error comes back as it was
+        let error: io::Error = Error::from(io::Error::from_raw_os_error(2)).into();
+        assert_eq!(error.raw_os_error(), Some(2));
+
+        // And it round trips through io::Error
+        let error: io::Error = Error::new(io::ErrorKind::InvalidInput, "Bad value.").with_offset(4).into();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(Error::from(error).offset(), Some(4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_reader_borrows() -> std::io::Result<()> {
+        let data = std::fs::read("./file.bin")?;
+
+        let (header, mime_types) = {
+            let mut reader = SliceReader::new(&data);
+            let header = reader.read_bytes_ref(4)?;
+            reader.set_position(0x48);
+            let mime_types = [reader.read_nt_str_ref()?, reader.read_nt_str_ref()?];
+            (header, mime_types)
+        };
+
+        // Borrowed straight from `data`, outliving the reader
+        assert_eq!(header, [0x42, 0x49, 0x4E, 0x00]);
+        assert!(std::ptr::eq(header.as_ptr(), data.as_ptr()));
+        assert_eq!(mime_types, ["image/png", "audio/mpeg"]);
+
+        // The usual BinaryReader / BinaryUtils surface works on it too
+        let mut reader = SliceReader::new(&data);
+        reader.ignore(4)?;
+        assert_eq!(reader.read_le_u16()?, 3);
+        assert_eq!(reader.buffer_size()?, data.len());
+        reader.seek(io::SeekFrom::Start(0x48))?;
+        assert_eq!(reader.read_null_terminated_string()?, "image/png");
+        assert_eq!(reader.read_cstr_ref()?.to_bytes(), b"audio/mpeg");
+
+        let mut reader = SliceReader::new(b"abc");
+        assert_eq!(reader.read_bytes_ref(4).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.read_cstr_ref().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.read_str_ref(3)?, "abc");
+
+        Ok(())
+    }
+
+    #[test]
+    fn bulk_slices() -> std::io::Result<()> {
+        let positions: Vec<f32> = (0..1000).map(|index| index as f32 * 0.25).collect();
+        let indices: [u16; 3] = [0x0102, 0x0304, 0x0506];
+
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_u16_slice(&indices, Endianness::Big)?;
+        stream.write_f32_slice(&positions, Endianness::Little)?;
+        stream.write_f32_slice(&positions, Endianness::Big)?;
+        assert_eq!(&stream.get_ref()[..6], [1, 2, 3, 4, 5, 6]);
+
+        stream.set_position(0);
+        let mut read_indices = [0u16; 3];
+        stream.read_u16_slice_into(&mut read_indices, Endianness::Big)?;
+        assert_eq!(read_indices, indices);
+        assert_eq!(stream.read_f32_vec(1000, Endianness::Little)?, positions);
+        assert_eq!(stream.read_f32_vec(1000, Endianness::Big)?, positions);
+
+        // Matches the element by element methods
+        stream.set_position(6 + 4000 + 4);
+        assert_eq!(stream.read_be_f32()?, 0.25);
+        stream.seek(io::SeekFrom::End(-4))?;
+        assert_eq!(stream.read_i64_vec(1, Endianness::Big).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        Ok(())
+    }
+
+    /// Hands out at most one byte per call and fails every other call with
+    /// `ErrorKind::Interrupted`, like a slow pipe or socket
+    struct Fragmenting<StreamT> {
+        stream: StreamT,
+        interrupt: bool,
+    }
+
+    impl<StreamT> Fragmenting<StreamT> {
+        fn new(stream: StreamT) -> Self {
+            Fragmenting { stream, interrupt: true }
+        }
+
+        fn interrupt(&mut self) -> io::Result<()> {
+            self.interrupt = !self.interrupt;
+            match self.interrupt {
+                true => Err(io::Error::from(io::ErrorKind::Interrupted)),
+                false => Ok(()),
+            }
+        }
+    }
+
+    impl<StreamT: io::Read> io::Read for Fragmenting<StreamT> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt()?;
+            let length = buf.len().min(1);
+            self.stream.read(&mut buf[..length])
+        }
+    }
+
+    impl<StreamT: Write> Write for Fragmenting<StreamT> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.interrupt()?;
+            let length = buf.len().min(1);
+            self.stream.write(&buf[..length])
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.stream.flush()
+        }
+    }
+
+    impl<StreamT: Seek> Seek for Fragmenting<StreamT> {
+        fn seek(&mut self, position: io::SeekFrom) -> io::Result<u64> {
+            self.stream.seek(position)
+        }
+    }
+
+    #[test]
+    fn short_reads_and_writes() -> std::io::Result<()> {
+        let mut writer = Fragmenting::new(Cursor::new(Vec::<u8>::new()));
+        writer.write_be_u32(0x42494E00)?;
+        writer.write_le_u64(u64::MAX - 1)?;
+        writer.write_le_f64(1.5)?;
+        writer.write_nt_string("fragmented")?;
+        writer.write_string("tail")?;
+
+        let data = writer.stream.into_inner();
+        let mut reader = Fragmenting::new(Cursor::new(&data));
+        assert_eq!(&*reader.read_raw(4)?, b"BIN\0");
+        assert_eq!(reader.read_le_u64()?, u64::MAX - 1);
+        assert_eq!(reader.read_le_f64()?, 1.5);
+        assert_eq!(reader.read_null_terminated_string()?, "fragmented");
+        assert_eq!(reader.read_fixed_size_string(4)?, "tail");
+        assert_eq!(reader.read_u8().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        // Reads spanning the seam of a chain or a BufReader refill
+        let mut chained = io::Read::chain(&data[..3], &data[3..]);
+        assert_eq!(chained.read_be_u32()?, 0x42494E00);
+
+        let mut buffered = BufReader::with_capacity(3, Cursor::new(&data));
+        assert_eq!(&*buffered.read_raw(12)?, &data[..12]);
+        assert_eq!(buffered.read_le_f64()?, 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_limits() -> std::io::Result<()> {
+        // A corrupt u32 prefix claiming 4 GiB only allocates what the stream holds
+        let mut corrupt = Cursor::new(vec![0xFF, 0xFF, 0xFF, 0xFF, b'a', b'b']);
+        let error = corrupt.read_size_prefixed_bytes::<u32>(Endianness::Little).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let limits = ReadLimits { max_allocation: 16, max_string_length: 4, ..ReadLimits::default() };
+        let mut reader = LimitedReader::new(Cursor::new(vec![0xFF, 0xFF, 0xFF, 0xFF, b'a', b'b']), limits);
+        let error = reader.read_size_prefixed_bytes::<u32>(Endianness::Little).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut reader = LimitedReader::new(Cursor::new(b"name\0longer name\0".to_vec()), limits);
+        assert_eq!(reader.read_null_terminated_string()?, "name");
+        assert_eq!(reader.read_null_terminated_string().unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.read_raw(17).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // Lengths can also be checked against the rest of the stream
+        let limits = ReadLimits { check_remaining: true, ..ReadLimits::default() };
+        let mut reader = LimitedReader::new_seekable(Cursor::new(vec![0u8; 8]), limits)?;
+        assert_eq!(reader.read_raw(9).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.read_raw(8)?.len(), 8);
+
+        // The limits hold for every method, including through generic code and derives
+        fn parse_name<R: BinaryReader>(reader: &mut R) -> io::Result<String> {
+            reader.read_fixed_size_utf16_string(6, Endianness::Little, false)
+        }
+
+        #[derive(BinRead, Debug)]
+        struct Named {
+            length: u8,
+            #[binre(count = length)]
+            name: String,
+        }
+
+        let limits = ReadLimits { max_allocation: 64, max_string_length: 8, ..ReadLimits::default() };
+        let mut reader = LimitedReader::new(Cursor::new(vec![b'a'; 32]), limits);
+        assert_eq!(parse_name(&mut reader).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.total_read(), 0);
+        assert_eq!(reader.read_vec::<u64>(9, Endianness::Little).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.read_u32_vec(17, Endianness::Little).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut reader = LimitedReader::new(Cursor::new([9, b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i']), limits);
+        assert_eq!(Named::read_from(&mut reader, Endianness::Little).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        reader.get_mut().set_position(0);
+        reader.get_mut().get_mut()[0] = 8;
+        let named = Named::read_from(&mut reader, Endianness::Little)?;
+        assert_eq!((named.length, named.name.as_str()), (8, "abcdefgh"));
+
+        // The total limit also bounds generic reads
+        let limits = ReadLimits { max_total: 6, ..ReadLimits::default() };
+        let mut reader = LimitedReader::new(Cursor::new(vec![0u8; 64]), limits);
+        assert_eq!(reader.read_value::<u32>(Endianness::Little)?, 0);
+        assert_eq!(reader.read_value::<u32>(Endianness::Little).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.total_read(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn size_prefixes() -> std::io::Result<()> {
+        let mut stream = Cursor::new(vec![0x00, 0x03, b'a', b'b', b'c']);
+        assert_eq!(stream.read_size_prefixed_string::<i16>(Endianness::Big)?, "abc");
+        stream.set_position(1);
+        assert_eq!(stream.read_size_prefixed_string::<u8>(Endianness::Little)?, "abc");
+
+        let mut stream = Cursor::new(vec![0xFF, 0xFF, 0xFF, 0xFE]);
+        let error = stream.read_size_prefixed_bytes::<i32>(Endianness::Big).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut stream = Cursor::new(vec![0x05, 0x00]);
+        let error = stream.read_size_prefixed_bytes::<u32>(Endianness::Little).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_size_prefixed_slice::<u16, u32>(&[1, 2], Endianness::Big, LengthUnit::Elements)?;
+        stream.write_size_prefixed_slice::<i64, u16>(&[3, 4, 5], Endianness::Little, LengthUnit::Bytes)?;
+        stream.write_size_prefixed_bytes::<u128>(&[6], Endianness::Big)?;
+
+        assert_eq!(&stream.get_ref()[..10], [0, 2, 0, 0, 0, 1, 0, 0, 0, 2]);
+        assert_eq!(&stream.get_ref()[10..18], 6u64.to_le_bytes());
+
+        stream.set_position(0);
+        assert_eq!(
+            stream.read_size_prefixed_vec::<u16, u32>(Endianness::Big, LengthUnit::Elements)?,
+            [1, 2]
+        );
+        assert_eq!(
+            stream.read_size_prefixed_vec::<i64, u16>(Endianness::Little, LengthUnit::Bytes)?,
+            [3, 4, 5]
+        );
+        assert_eq!(*stream.read_size_prefixed_bytes::<u128>(Endianness::Big)?, [6]);
+
+        let mut stream = Cursor::new(vec![3, 0, 1, 0, 2]);
+        let error = stream
+            .read_size_prefixed_vec::<u8, u16>(Endianness::Little, LengthUnit::Bytes)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        let error = stream
+            .write_size_prefixed_slice::<i8, u8>(&[0; 128], Endianness::Little, LengthUnit::Elements)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        // A byte length that doesn't fit is only known after the elements, which are
+        // left in place with the stream after them
+        let error = stream
+            .write_size_prefixed_slice::<u8, u16>(&[0; 200], Endianness::Little, LengthUnit::Bytes)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(stream.position(), 401);
+
+        Ok(())
+    }
+
+    #[test]
+    fn plain_write_sinks() -> std::io::Result<()> {
+        let mut sink = Vec::<u8>::new();
+        sink.write_u16(0xCAFE, Endianness::Big)?;
+        sink.write_size_prefixed_string::<u8>("hi", Endianness::Little)?;
+        sink.write_uleb128(300)?;
+        assert_eq!(sink, [0xCA, 0xFE, 2, b'h', b'i', 0xAC, 0x02]);
+
+        let mut reader: &[u8] = &sink;
+        reader.discard(2)?;
+        assert_eq!(reader.read_size_prefixed_string::<u8>(Endianness::Little)?, "hi");
+        assert_eq!(reader.discard(3).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut sink = Vec::<u8>::new();
+        sink.write_value(&Shape::Rect(1, 2), Endianness::Little)?;
+        assert_eq!(sink, [0x10, 1, 0, 0, 2]);
+
+        // Alignment depends on the position, so it needs the seekable path
+        let padded = Padded { tag: 7, value: 0x1234, last: 9 };
+        let error = Error::from(sink.write_value(&padded, Endianness::Big).unwrap_err());
+        assert_eq!((error.kind(), error.path_string()), (io::ErrorKind::Unsupported, "value".to_owned()));
+        assert_eq!(error.offset(), None);
+
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        let size = stream.reserve_u32(Endianness::Little)?;
+        stream.write_value(&[1u8, 2, 3], Endianness::Little)?;
+        stream.patch_size(&size, 4)?;
+        assert_eq!(stream.into_inner(), [3, 0, 0, 0, 1, 2, 3]);
+
+        let mut stream = Cursor::new(Vec::<u8>::new());
+        stream.write_size_prefixed_slice::<u8, u16>(&[1, 2], Endianness::Little, LengthUnit::Bytes)?;
+        stream.write_size_prefixed_slice::<u8, u16>(&[3], Endianness::Little, LengthUnit::Elements)?;
+        assert_eq!(stream.into_inner(), [4, 1, 0, 2, 0, 1, 3, 0]);
+
+        Ok(())
+    }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[binre(big)]
+    struct Padded {
+        tag: u8,
+        #[binre(align = 4, fill = b"\xCD")]
+        value: u16,
+        #[binre(pad = 5, fill = b"PAD")]
+        last: u8,
+    }
+
+    #[test]
+    fn padding_fills() -> std::io::Result<()> {
+        let mut sink = Vec::<u8>::new();
+        sink.write_padding(7, b"PAD")?;
+        assert_eq!(sink, b"PADPADP");
+        assert!(sink.write_padding(1, &[]).is_err());
+
+        let mut stream = Cursor::new(vec![0xFFu8; 8]);
+        stream.write_u8(1)?;
+        stream.pad_to_alignment(4, &[0])?;
+        stream.write_u8(2)?;
+        assert_eq!(stream.get_ref()[..5], [1, 0, 0, 0, 2]);
+
+        stream.set_position(1);
+        stream.skip_to_alignment(4, &[0])?;
+        assert_eq!(stream.read_u8()?, 2);
+        stream.set_position(5);
+        let error = Error::from(stream.skip_padding(2, &[0xFF, 0xEE]).unwrap_err());
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!((error.expected(), error.found()), (Some("238"), Some("255")));
+
+        let value = Padded { tag: 7, value: 0x1234, last: 9 };
+        let mut output = Cursor::new(Vec::<u8>::new());
+        value.write_to_seekable(&mut output, Endianness::Big)?;
+        assert_eq!(
+            output.get_ref().as_slice(),
+            [7, 0xCD, 0xCD, 0xCD, 0x12, 0x34, b'P', b'A', b'D', b'P', b'A', 9]
+        );
+
+        // `write_value` takes the plain path even on a seekable stream
+        let mut seekable = Cursor::new(Vec::<u8>::new());
+        let error = seekable.write_value(&value, Endianness::Big).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+        let mut seekable = Cursor::new(Vec::<u8>::new());
+        seekable.write_value_seekable(&value, Endianness::Big)?;
+        assert_eq!(seekable.get_ref(), output.get_ref());
+
+        output.set_position(0);
+        assert_eq!(Padded::read_from(&mut output, Endianness::Big)?, value);
+
+        output.get_mut()[8] = b'X';
+        output.set_position(0);
+        let error = Error::from(Padded::read_from(&mut output, Endianness::Big).unwrap_err());
+        assert_eq!((error.path_string(), error.offset()), ("last".to_owned(), Some(11)));
+
+        Ok(())
+    }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[binre(little)]
+    struct SubFile {
+        count: u8,
+        #[binre(align = 4)]
+        name: FilePtr<u32, String>,
+    }
+
+    #[test]
+    fn nested_base_offsets() -> std::io::Result<()> {
+        let write_sub_file = |stream: &mut BaseStream<Cursor<Vec<u8>>>| -> io::Result<()> {
+            stream.write_u8(1)?;
+            stream.pad_to_alignment(4, &[0])?;
+            let offset = stream.reserve_u32(Endianness::Little)?;
+            stream.patch_offset(&offset, 0)?;
+            stream.write_nt_string("inner")
+        };
+
+        let mut standalone = BaseStream::new(Cursor::new(Vec::<u8>::new()));
+        write_sub_file(&mut standalone)?;
+        let standalone = standalone.into_inner().into_inner();
+
+        let mut nested = BaseStream::new(Cursor::new(Vec::<u8>::new()));
+        nested.write_all(b"HEADER")?;
+        nested.with_base(|stream| {
+            assert_eq!(stream.tell()?, 0);
+            write_sub_file(stream)
+        })?;
+        assert_eq!(nested.tell()?, 6 + standalone.len() as u64);
+        assert_eq!(&nested.get_ref().get_ref()[6..], standalone.as_slice());
+
+        nested.seek(io::SeekFrom::Start(6))?;
+        nested.push_base()?;
+        let sub_file = SubFile::read_from(&mut nested, Endianness::Little)?;
+        assert_eq!(sub_file.count, 1);
+        assert_eq!((sub_file.name.offset, sub_file.name.value.as_deref()), (8, Some("inner")));
+        assert_eq!(nested.seek(io::SeekFrom::Current(-100)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(nested.tell()?, 8);
+
+        nested.get_mut().set_position(2);
+        assert_eq!(nested.tell().unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        nested.get_mut().set_position(6);
+        assert_eq!(nested.seek(io::SeekFrom::Start(8))?, 8);
+
+        assert_eq!(nested.pop_base(), Some(6));
+        assert_eq!(nested.tell()?, 14);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sub_stream_windows() -> std::io::Result<()> {
+        let mut archive = Cursor::new(b"HEAD\x05\x00helloTAIL".to_vec());
+
+        let error = SubStream::new(&mut archive, 10, 7).err().map(|error| error.kind());
+        assert_eq!(error, Some(io::ErrorKind::InvalidInput));
+        assert!(SubStream::new(&mut archive, u64::MAX, 1).is_err());
+
+        let mut entry = SubStream::new(&mut archive, 4, 7)?;
+        assert_eq!(entry.buffer_size()?, 7);
+        assert_eq!(entry.read_size_prefixed_string::<u16>(Endianness::Little)?, "hello");
+        assert_eq!(entry.tell()?, 7);
+        assert_eq!(entry.read_u8().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        entry.seek(io::SeekFrom::End(-5))?;
+        assert_eq!(*entry.read_raw(2)?, *b"he");
+        assert!(entry.seek(io::SeekFrom::Current(-10)).is_err());
+
+        entry.seek(io::SeekFrom::Start(2))?;
+        entry.write_all(b"HELLO")?;
+        let error = entry.write_u8(b'!').unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        entry.seek(io::SeekFrom::Start(5))?;
+        assert_eq!(entry.write(b"xyz")?, 2);
+        assert_eq!(entry.write(b"z")?, 0);
+
+        assert_eq!(archive.get_ref().as_slice(), b"HEAD\x05\x00HELxyTAIL");
+
+        Ok(())
+    }
+
+    #[test]
+    fn peeks_and_rollbacks() -> std::io::Result<()> {
+        let mut stream = Cursor::new(vec![0x03, 0x00, 0x00, 0x00, 0xAA, 0xBB]);
+        assert_eq!(stream.peek_u32(Endianness::Little)?, 3);
+        assert_eq!(*stream.peek_raw(2)?, [0x03, 0x00]);
+        assert_eq!(stream.peek_value::<(u8, u8)>(Endianness::Big)?, (3, 0));
+        assert_eq!(stream.tell()?, 0);
+        assert!(stream.peek_u64(Endianness::Big).is_err());
+        assert_eq!(stream.tell()?, 0);
+
+        // A "v3" header needs a version of 3 and a trailing u32, which isn't there
+        let v3 = stream.with_rollback(|stream| {
+            let version = stream.read_u32(Endianness::Little)?;
+            let extra = stream.read_u32(Endianness::Little)?;
+            Ok((version, extra))
+        });
+        assert!(v3.is_err());
+        assert_eq!(stream.tell()?, 0);
+        let version = stream.with_rollback(|stream| stream.read_u32(Endianness::Little))?;
+        assert_eq!((version, stream.tell()?), (3, 4));
+
+        {
+            let mut guard = stream.rollback_guard()?;
+            assert_eq!(guard.read_u8()?, 0xAA);
+        }
+        assert_eq!(stream.tell()?, 4);
+
+        let mut guard = stream.rollback_guard()?;
+        assert_eq!(guard.read_u16(Endianness::Big)?, 0xAABB);
+        guard.commit();
+        assert_eq!(stream.tell()?, 6);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,29 @@
+use super::endianness::Endianness;
+
+/// A slot reserved in a writer by `SeekableWriter::reserve_u16/u32/u64` that is
+/// filled in later with `SeekableWriter::patch`, `patch_offset` or `patch_size`,
+/// once the value is known (EG table offsets and chunk sizes).
+#[must_use = "a reserved slot stays zeroed unless it is patched"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placeholder {
+    pub(crate) position: u64,
+    pub(crate) size: usize,
+    pub(crate) endianness: Endianness,
+}
+
+impl Placeholder {
+    /// Stream position of the reserved slot
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Size of the reserved slot in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Largest value that fits in the reserved slot
+    pub fn max_value(&self) -> u64 {
+        u64::MAX >> (64 - self.size * 8)
+    }
+}
@@ -0,0 +1,260 @@
+use std::cell::RefCell;
+use std::io::{self, Error, ErrorKind, Read, Seek, SeekFrom};
+
+use super::binary_utils::BinaryUtils;
+
+/// Bounds applied by a `LimitedReader` when parsing untrusted input.
+///
+/// The default is unlimited; set only the fields you need:
+/// `ReadLimits { max_total: 64 << 20, ..ReadLimits::default() }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadLimits {
+    /// Largest byte buffer a single read may allocate
+    pub max_allocation: usize,
+    /// Longest string a single read may return, in bytes
+    pub max_string_length: usize,
+    /// Most bytes that may be read through the reader in total
+    pub max_total: u64,
+    /// Reject lengths larger than what is left in the stream before allocating. This
+    /// needs the size of the stream, so it only applies to readers created with
+    /// `LimitedReader::new_seekable`.
+    pub check_remaining: bool,
+}
+
+impl Default for ReadLimits {
+    fn default() -> Self {
+        ReadLimits {
+            max_allocation: usize::MAX,
+            max_string_length: usize::MAX,
+            max_total: u64::MAX,
+            check_remaining: false,
+        }
+    }
+}
+
+/// A reader enforcing `ReadLimits`.
+///
+/// `max_total` is enforced on every byte read through the wrapper. The length limits
+/// apply to every allocating read made on it: the `BinaryReader` methods announce the
+/// buffers, strings and sequences they are about to fill, and the wrapper checks them
+/// before handing out data. Generic code (`fn parse<R: BinaryReader>`), `BinRead`
+/// implementations and derived types are bound by them the same way.
+///
+/// The announced length is checked when it is known up front (EG a size prefix),
+/// otherwise (EG a null terminated string) the data is counted as it is read. The
+/// wrapper should be the stream being parsed; beneath a buffering reader it would
+/// count read ahead data as well.
+///
+/// Independently of any limit, the `BinaryReader` methods grow their buffers as data
+/// arrives, so a corrupt length fails with `UnexpectedEof` once the stream runs out
+/// instead of allocating the full length up front.
+///
+/// # Scope of the length limits
+///     The announcements are kept per thread, not per stream, as the `BinaryReader`
+///     methods are implemented once for every stream and can't tell a `LimitedReader`
+///     apart. A read on the wrapper checks every allocation in progress on the
+///     thread, including ones announced by a read on another stream that is still
+///     running (EG a `BinRead` implementation reading from the wrapper while filling
+///     a `Vec` from a side buffer), so that allocation is held to these limits too.
+///     Reads on other streams that don't go through the wrapper are never checked.
+///     Keep unrelated parsing out of a limited read, or run it on another thread,
+///     when it must not be bound by the limits. `max_total` only counts the wrapper's
+///     own bytes.
+pub struct LimitedReader<StreamT> {
+    stream: StreamT,
+    limits: ReadLimits,
+    total_read: u64,
+    /// Position and size of the stream, known when created by `new_seekable`
+    bounds: Option<(u64, u64)>,
+}
+
+impl<StreamT> LimitedReader<StreamT> {
+    pub fn new(stream: StreamT, limits: ReadLimits) -> Self {
+        LimitedReader {
+            stream,
+            limits,
+            total_read: 0,
+            bounds: None,
+        }
+    }
+
+    pub fn limits(&self) -> &ReadLimits {
+        &self.limits
+    }
+
+    /// Bytes read through the wrapper so far
+    pub fn total_read(&self) -> u64 {
+        self.total_read
+    }
+
+    pub fn get_ref(&self) -> &StreamT {
+        &self.stream
+    }
+
+    /// Moving the stream through this reference is not tracked by `check_remaining`
+    pub fn get_mut(&mut self) -> &mut StreamT {
+        &mut self.stream
+    }
+
+    pub fn into_inner(self) -> StreamT {
+        self.stream
+    }
+
+    /// Rejects announced allocations that exceed the limits, or the rest of the stream
+    fn check_allocations(&self) -> io::Result<()> {
+        let string_limit = self
+            .limits
+            .max_string_length
+            .min(self.limits.max_allocation);
+
+        ALLOCATIONS.with_borrow(|allocations| {
+            for allocation in allocations {
+                let limit = match allocation.kind {
+                    AllocationKind::String => string_limit,
+                    AllocationKind::Buffer | AllocationKind::Elements => self.limits.max_allocation,
+                };
+                if allocation.length.unwrap_or(allocation.consumed) > limit {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Length exceeds the read limit.",
+                    ));
+                }
+
+                // Elements are measured in memory, not in bytes of the stream
+                if !self.limits.check_remaining || allocation.kind == AllocationKind::Elements {
+                    continue;
+                }
+                if let (Some((position, size)), Some(length)) = (self.bounds, allocation.length) {
+                    let unread = length.saturating_sub(allocation.consumed) as u64;
+                    if unread > size.saturating_sub(position) {
+                        return Err(Error::new(
+                            ErrorKind::UnexpectedEof,
+                            "Length is larger than the rest of the stream.",
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<StreamT: Seek> LimitedReader<StreamT> {
+    /// Like `new`, but also looks up the size of the stream so `check_remaining`
+    /// can be enforced
+    ///
+    /// # Errors
+    ///     io::Error - Error bubbled up from seeking
+    pub fn new_seekable(mut stream: StreamT, limits: ReadLimits) -> io::Result<Self> {
+        let bounds = (stream.tell()?, stream.buffer_size()? as u64);
+
+        Ok(LimitedReader {
+            bounds: Some(bounds),
+            ..LimitedReader::new(stream, limits)
+        })
+    }
+}
+
+/// Fails once `max_total` bytes have been read, or when an announced allocation
+/// exceeds the limits
+impl<StreamT: Read> Read for LimitedReader<StreamT> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.check_allocations()?;
+
+        let allowance = self.limits.max_total - self.total_read;
+        if allowance == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Total read limit exceeded.",
+            ));
+        }
+
+        let length = buf
+            .len()
+            .min(usize::try_from(allowance).unwrap_or(usize::MAX));
+        let read = self.stream.read(&mut buf[..length])?;
+        self.total_read += read as u64;
+        if let Some((position, _)) = &mut self.bounds {
+            *position += read as u64;
+        }
+
+        ALLOCATIONS.with_borrow_mut(|allocations| {
+            for allocation in allocations {
+                allocation.consumed = allocation.consumed.saturating_add(read);
+            }
+        });
+
+        Ok(read)
+    }
+}
+
+impl<StreamT: Seek> Seek for LimitedReader<StreamT> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let position = self.stream.seek(position)?;
+        if let Some((tracked, _)) = &mut self.bounds {
+            *tracked = position;
+        }
+
+        Ok(position)
+    }
+}
+
+// ------------------------------------------------------------------------------- Allocations
+/// What an allocating read is about to fill
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AllocationKind {
+    /// Bytes taken straight from the stream
+    Buffer,
+    /// A string, measured in bytes of the stream
+    String,
+    /// A sequence of values, measured in bytes of memory
+    Elements,
+}
+
+struct Allocation {
+    kind: AllocationKind,
+    /// In bytes, None when the read ends at a terminator
+    length: Option<usize>,
+    /// Bytes read through a `LimitedReader` since the announcement
+    consumed: usize,
+}
+
+thread_local! {
+    /// Allocating reads in progress on this thread, innermost last, whatever stream
+    /// they are made on. This is how the `BinaryReader` methods, implemented once for
+    /// every stream, reach the limits of a `LimitedReader` underneath them; see
+    /// "Scope of the length limits" on `LimitedReader`.
+    static ALLOCATIONS: RefCell<Vec<Allocation>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Announces an allocating read until it is dropped
+pub(crate) struct AllocationScope;
+
+/// Announce an allocating read to any `LimitedReader` it goes through. Keep the
+/// returned scope alive for the duration of the read.
+///
+/// # Parameters
+///     `kind`   - What is being allocated
+///     `length` - Size in bytes, None when the read ends at a terminator
+pub(crate) fn announce_allocation(kind: AllocationKind, length: Option<usize>) -> AllocationScope {
+    ALLOCATIONS.with_borrow_mut(|allocations| {
+        allocations.push(Allocation {
+            kind,
+            length,
+            consumed: 0,
+        })
+    });
+
+    AllocationScope
+}
+
+impl Drop for AllocationScope {
+    fn drop(&mut self) {
+        ALLOCATIONS.with_borrow_mut(|allocations| allocations.pop());
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::{Seek, SeekFrom};
+use std::ops::{Deref, DerefMut};
+
+/// Returns a stream to the position it had when the guard was created, unless the
+/// guard is committed first. Created by `BinaryUtils::rollback_guard`.
+///
+/// The guard dereferences to the stream, so reads go through it. Any early return
+/// (EG with `?`) drops the guard and rewinds the stream; errors while rewinding are
+/// ignored, as `Drop` can't report them.
+#[must_use = "the stream is rewound as soon as the guard is dropped"]
+pub struct RollbackGuard<'a, StreamT: Seek + ?Sized> {
+    stream: &'a mut StreamT,
+    start: u64,
+    committed: bool,
+}
+
+impl<'a, StreamT: Seek + ?Sized> RollbackGuard<'a, StreamT> {
+    pub(crate) fn new(stream: &'a mut StreamT, start: u64) -> Self {
+        RollbackGuard {
+            stream,
+            start,
+            committed: false,
+        }
+    }
+
+    /// Position the stream is rewound to
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Keep the stream where it is
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<StreamT: Seek + ?Sized> Deref for RollbackGuard<'_, StreamT> {
+    type Target = StreamT;
+
+    fn deref(&self) -> &StreamT {
+        self.stream
+    }
+}
+
+impl<StreamT: Seek + ?Sized> DerefMut for RollbackGuard<'_, StreamT> {
+    fn deref_mut(&mut self) -> &mut StreamT {
+        self.stream
+    }
+}
+
+impl<StreamT: Seek + ?Sized> Drop for RollbackGuard<'_, StreamT> {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = self.stream.seek(SeekFrom::Start(self.start));
+        }
+    }
+}
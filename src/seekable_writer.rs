@@ -0,0 +1,260 @@
+use num_traits::PrimInt;
+use std::io::{self, Error, ErrorKind, Seek, SeekFrom, Write};
+
+use super::binary_traits::BinWrite;
+use super::binary_utils::BinaryUtils;
+use super::binary_writer::{encode_size_prefix, write_size_prefix, BinaryWriter};
+use super::endianness::Endianness;
+use super::length_unit::LengthUnit;
+use super::placeholder::Placeholder;
+
+/// Writer operations that need to move around in the stream.
+///
+/// `BinaryWriter` only requires `Write`, so it also works on sinks like `Vec<u8>`,
+/// `Stdout` or a socket. Back-patching reserved slots, alignment padding, size
+/// prefixed slices (whose byte length is patched in afterwards) and values that align
+/// themselves need `Seek` as well and live here; they are implemented for every
+/// `Write + Seek` stream.
+pub trait SeekableWriter: BinaryWriter + Seek {
+    fn write_value_seekable<T: BinWrite + ?Sized>(
+        &mut self,
+        value: &T,
+        endianness: Endianness,
+    ) -> io::Result<()>
+    where
+        Self: Sized;
+    fn write_size_prefixed_slice<PrefixT: PrimInt, T: BinWrite>(
+        &mut self,
+        values: &[T],
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> io::Result<()>
+    where
+        Self: Sized;
+
+    fn reserve_u16(&mut self, endianness: Endianness) -> io::Result<Placeholder>;
+    fn reserve_u32(&mut self, endianness: Endianness) -> io::Result<Placeholder>;
+    fn reserve_u64(&mut self, endianness: Endianness) -> io::Result<Placeholder>;
+    fn patch(&mut self, placeholder: &Placeholder, value: u64) -> io::Result<()>;
+    fn patch_offset(&mut self, placeholder: &Placeholder, base: u64) -> io::Result<()>;
+    fn patch_size(&mut self, placeholder: &Placeholder, start: u64) -> io::Result<()>;
+
+    fn pad_to_alignment(&mut self, alignment: u64, fill: &[u8]) -> io::Result<()>;
+}
+
+impl<StreamT: Write + Seek> SeekableWriter for StreamT {
+    /// Write any `BinWrite` type to stream, including ones that need to seek (EG
+    /// derived types with `align`)
+    ///
+    /// # Parameters
+    ///     `value`      - The variable to write to the stream
+    ///     `endianness` - Endianness of the stream to write to
+    ///
+    /// # Errors
+    ///     io::Error - Whatever the type's `BinWrite` implementation reports
+    fn write_value_seekable<T: BinWrite + ?Sized>(
+        &mut self,
+        value: &T,
+        endianness: Endianness,
+    ) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        value.write_to_seekable(self, endianness)
+    }
+
+    /// Write a size prefixed sequence of `BinWrite` values to stream. Errors carry the
+    /// index of the failing element.
+    ///
+    /// # Parameters
+    ///     `values`     - The values to write to the stream
+    ///     `endianness` - Endianness of the prefix and the elements
+    ///     `unit`       - Whether the prefix counts elements or the bytes they take up
+    ///
+    /// # Errors
+    ///     io::Error               - Whatever the element's `BinWrite` implementation reports
+    ///     io::Error::InvalidInput - When the length does not fit in `PrefixT`. For a byte
+    ///                               length this is found after the elements are written,
+    ///                               and the stream is left after them
+    fn write_size_prefixed_slice<PrefixT: PrimInt, T: BinWrite>(
+        &mut self,
+        values: &[T],
+        endianness: Endianness,
+        unit: LengthUnit,
+    ) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        if unit == LengthUnit::Elements {
+            write_size_prefix::<Self, PrefixT>(self, values.len(), endianness)?;
+            return values.write_to_seekable(self, endianness);
+        }
+
+        // The byte length is only known once the elements are written, so the prefix
+        // is reserved and filled in afterwards
+        let prefix_position = self.tell()?;
+        write_size_prefix::<Self, PrefixT>(self, 0, endianness)?;
+        let start = self.tell()?;
+        values.write_to_seekable(self, endianness)?;
+        let end = self.tell()?;
+
+        // Encoded before seeking back, so a length that doesn't fit leaves the stream
+        // after the elements
+        let length = usize::try_from(end - start).or(Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Length does not fit in the size prefix.",
+        )))?;
+        let (prefix, width) = encode_size_prefix::<PrefixT>(length, endianness)?;
+
+        self.seek(SeekFrom::Start(prefix_position))?;
+        let result = self.write_all(&prefix[..width]);
+        self.seek(SeekFrom::Start(end))?;
+
+        result
+    }
+
+    /// Reserve a zeroed u16 slot to be patched once its value is known
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness the value will be patched in
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///
+    /// # Returns
+    ///     Placeholder - Handle to pass to `patch`, `patch_offset` or `patch_size`
+    fn reserve_u16(&mut self, endianness: Endianness) -> io::Result<Placeholder> {
+        let position = self.tell()?;
+        self.write_u16(0, endianness)?;
+
+        Ok(Placeholder { position, size: std::mem::size_of::<u16>(), endianness })
+    }
+
+    /// Reserve a zeroed u32 slot to be patched once its value is known
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness the value will be patched in
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///
+    /// # Returns
+    ///     Placeholder - Handle to pass to `patch`, `patch_offset` or `patch_size`
+    fn reserve_u32(&mut self, endianness: Endianness) -> io::Result<Placeholder> {
+        let position = self.tell()?;
+        self.write_u32(0, endianness)?;
+
+        Ok(Placeholder { position, size: std::mem::size_of::<u32>(), endianness })
+    }
+
+    /// Reserve a zeroed u64 slot to be patched once its value is known
+    ///
+    /// # Parameters
+    ///     `endianness` - Endianness the value will be patched in
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///
+    /// # Returns
+    ///     Placeholder - Handle to pass to `patch`, `patch_offset` or `patch_size`
+    fn reserve_u64(&mut self, endianness: Endianness) -> io::Result<Placeholder> {
+        let position = self.tell()?;
+        self.write_u64(0, endianness)?;
+
+        Ok(Placeholder { position, size: std::mem::size_of::<u64>(), endianness })
+    }
+
+    /// Write `value` into a reserved slot. The stream position is left unchanged.
+    ///
+    /// # Parameters
+    ///     `placeholder` - The slot returned by one of the `reserve_*` functions
+    ///     `value`       - The value to store in the slot
+    ///
+    /// # Errors
+    ///     io::Error during seek or write
+    ///     io::Error::InvalidInput when `value` does not fit in the slot
+    fn patch(&mut self, placeholder: &Placeholder, value: u64) -> io::Result<()> {
+        if value > placeholder.max_value() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Value does not fit in the reserved slot.",
+            ));
+        }
+
+        let return_position = self.tell()?;
+        self.seek(SeekFrom::Start(placeholder.position))?;
+        match placeholder.size {
+            2 => self.write_u16(value as u16, placeholder.endianness)?,
+            4 => self.write_u32(value as u32, placeholder.endianness)?,
+            _ => self.write_u64(value, placeholder.endianness)?,
+        }
+        self.seek(SeekFrom::Start(return_position))?;
+
+        Ok(())
+    }
+
+    /// Patch a reserved slot with the current stream position, relative to `base`.
+    /// Call this right before writing the block the slot points to.
+    ///
+    /// # Parameters
+    ///     `placeholder` - The slot returned by one of the `reserve_*` functions
+    ///     `base`        - Position the offset is relative to (zero for absolute offsets)
+    ///
+    /// # Errors
+    ///     io::Error during seek or write
+    ///     io::Error::InvalidInput when the offset does not fit in the slot or `base` is past the current position
+    fn patch_offset(&mut self, placeholder: &Placeholder, base: u64) -> io::Result<()> {
+        let offset = self
+            .tell()?
+            .checked_sub(base)
+            .ok_or(Error::from(ErrorKind::InvalidInput))?;
+
+        self.patch(placeholder, offset)
+    }
+
+    /// Patch a reserved slot with the number of bytes written since `start`.
+    /// Call this right after writing the block the slot measures.
+    ///
+    /// # Parameters
+    ///     `placeholder` - The slot returned by one of the `reserve_*` functions
+    ///     `start`       - Position the measured block started at
+    ///
+    /// # Errors
+    ///     io::Error during seek or write
+    ///     io::Error::InvalidInput when the size does not fit in the slot or `start` is past the current position
+    fn patch_size(&mut self, placeholder: &Placeholder, start: u64) -> io::Result<()> {
+        let size = self
+            .tell()?
+            .checked_sub(start)
+            .ok_or(Error::from(ErrorKind::InvalidInput))?;
+
+        self.patch(placeholder, size)
+    }
+
+    /// Write `fill` padding up to the nearest `alignment` boundary. Unlike
+    /// `BinaryUtils::next_alignment`, which only seeks, the gap is always written, so
+    /// it neither leaves a hole at the end of the stream nor keeps stale bytes when
+    /// overwriting.
+    ///
+    /// # Parameters
+    ///     `alignment` - The boundary to pad to, must be non-zero
+    ///     `fill`      - The pattern to repeat, see `BinaryWriter::write_padding`
+    ///
+    /// # Errors
+    ///     io::Error during write
+    ///     io::Error::OutOfMemory  - Next alignment results in overflow of stream pos
+    ///     io::Error::InvalidInput - Alignment passed was zero, or `fill` is empty
+    fn pad_to_alignment(&mut self, alignment: u64, fill: &[u8]) -> io::Result<()> {
+        if alignment == 0 {
+            return Err(Error::from(ErrorKind::InvalidInput));
+        }
+
+        let position = self.tell()?;
+        let length = position
+            .checked_next_multiple_of(alignment)
+            .and_then(|target| usize::try_from(target - position).ok())
+            .ok_or(Error::from(ErrorKind::OutOfMemory))?;
+
+        self.write_padding(length, fill)
+    }
+}
@@ -0,0 +1,164 @@
+use std::ffi::CStr;
+use std::io::{self, BufRead, Error, ErrorKind, Read, Seek, SeekFrom};
+
+/// A reader over an in memory byte slice.
+///
+/// It implements `Read`, `BufRead` and `Seek`, so every `BinaryReader`/`BinaryUtils`
+/// method works on it. On top of those, the `*_ref` methods hand out slices and
+/// strings borrowed straight from the underlying data, without copying.
+#[derive(Debug, Clone, Copy)]
+pub struct SliceReader<'a> {
+    data: &'a [u8],
+    position: u64,
+}
+
+impl<'a> SliceReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        SliceReader { data, position: 0 }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    /// The whole underlying slice, independent of the position
+    pub fn get_ref(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The data from the position to the end (empty when positioned past the end)
+    pub fn remaining(&self) -> &'a [u8] {
+        let start = usize::try_from(self.position)
+            .unwrap_or(usize::MAX)
+            .min(self.data.len());
+
+        &self.data[start..]
+    }
+
+    /// Borrow `length` bytes from the data and advance past them
+    ///
+    /// # Parameters
+    ///     `length` - Number of bytes to borrow
+    ///
+    /// # Errors
+    ///     io::Error::UnexpectedEof when there isn't enough data left
+    ///
+    /// # Returns
+    ///     Slice of the underlying data
+    pub fn read_bytes_ref(&mut self, length: usize) -> io::Result<&'a [u8]> {
+        let remaining = self.remaining();
+        if remaining.len() < length {
+            return Err(Error::from(ErrorKind::UnexpectedEof));
+        }
+
+        self.position += length as u64;
+        Ok(&remaining[..length])
+    }
+
+    /// Borrow a `length` byte UTF-8 string from the data and advance past it
+    ///
+    /// # Parameters
+    ///     `length` - Length of the string in bytes
+    ///
+    /// # Errors
+    ///     io::Error::UnexpectedEof when there isn't enough data left
+    ///     io::Error::InvalidData   when the bytes are not valid UTF-8
+    ///
+    /// # Returns
+    ///     String slice of the underlying data
+    pub fn read_str_ref(&mut self, length: usize) -> io::Result<&'a str> {
+        let start = self.position;
+        let bytes = self.read_bytes_ref(length)?;
+
+        std::str::from_utf8(bytes).map_err(|_| {
+            self.position = start;
+            invalid_utf8()
+        })
+    }
+
+    /// Borrow a null terminated string from the data and advance past its terminator
+    ///
+    /// # Errors
+    ///     io::Error::UnexpectedEof when there is no terminator in the rest of the data
+    ///
+    /// # Returns
+    ///     CStr slice of the underlying data, including the terminator
+    pub fn read_cstr_ref(&mut self) -> io::Result<&'a CStr> {
+        let remaining = self.remaining();
+        let value = CStr::from_bytes_until_nul(remaining)
+            .map_err(|_| Error::from(ErrorKind::UnexpectedEof))?;
+
+        self.position += value.to_bytes_with_nul().len() as u64;
+        Ok(value)
+    }
+
+    /// Borrow a null terminated UTF-8 string from the data and advance past its
+    /// terminator
+    ///
+    /// # Errors
+    ///     io::Error::UnexpectedEof when there is no terminator in the rest of the data
+    ///     io::Error::InvalidData   when the bytes are not valid UTF-8
+    ///
+    /// # Returns
+    ///     String slice of the underlying data, without the terminator
+    pub fn read_nt_str_ref(&mut self) -> io::Result<&'a str> {
+        let start = self.position;
+        let value = self.read_cstr_ref()?;
+
+        value.to_str().map_err(|_| {
+            self.position = start;
+            invalid_utf8()
+        })
+    }
+}
+
+impl Read for SliceReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining();
+        let length = remaining.len().min(buf.len());
+
+        buf[..length].copy_from_slice(&remaining[..length]);
+        self.position += length as u64;
+
+        Ok(length)
+    }
+}
+
+impl BufRead for SliceReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining())
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount as u64;
+    }
+}
+
+/// Like `io::Cursor`, seeking past the end is allowed and later reads return no data
+impl Seek for SliceReader<'_> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match position {
+            SeekFrom::Start(offset) => {
+                self.position = offset;
+                return Ok(offset);
+            }
+            SeekFrom::End(offset) => (self.data.len() as u64, offset),
+            SeekFrom::Current(offset) => (self.position, offset),
+        };
+
+        self.position = base.checked_add_signed(offset).ok_or(Error::new(
+            ErrorKind::InvalidInput,
+            "Invalid seek to a negative or overflowing position.",
+        ))?;
+
+        Ok(self.position)
+    }
+}
+
+fn invalid_utf8() -> Error {
+    Error::new(ErrorKind::InvalidData, "Unable to convert bytes to string.")
+}
@@ -0,0 +1,147 @@
+use std::io::{self, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+use super::binary_utils::BinaryUtils;
+
+/// A window of `length` bytes starting at `offset` in a parent stream.
+///
+/// Positions, seeking and `BinaryUtils::buffer_size` are relative to the window, and
+/// reads stop at its end, so an embedded file can be parsed in place instead of being
+/// copied out with `read_raw`. Like `io::Cursor`, seeking past the end is allowed and
+/// later reads return no data.
+///
+/// When the parent is writable the window is as well, but writes stop at its end.
+/// The parent is repositioned before every access, so it may be a `&mut` borrow that
+/// is also used elsewhere.
+pub struct SubStream<StreamT> {
+    stream: StreamT,
+    offset: u64,
+    length: u64,
+    position: u64,
+}
+
+impl<StreamT: Seek> SubStream<StreamT> {
+    /// Create a window of `length` bytes starting at `offset` in `stream`
+    ///
+    /// # Parameters
+    ///     `stream` - The parent stream
+    ///     `offset` - Absolute position of the window in the parent
+    ///     `length` - Size of the window in bytes
+    ///
+    /// # Errors
+    ///     io::Error               - Error bubbled up from seeking
+    ///     io::Error::InvalidInput - The window does not fit in the parent
+    pub fn new(mut stream: StreamT, offset: u64, length: u64) -> io::Result<Self> {
+        let size = stream.buffer_size()? as u64;
+        if offset.checked_add(length).is_none_or(|end| end > size) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Sub-stream window goes past the end of the parent stream.",
+            ));
+        }
+
+        Ok(SubStream {
+            stream,
+            offset,
+            length,
+            position: 0,
+        })
+    }
+
+    /// Moves the parent to the absolute position matching the window position
+    fn seek_parent(&mut self) -> io::Result<()> {
+        self.stream
+            .seek(SeekFrom::Start(self.offset + self.position))?;
+
+        Ok(())
+    }
+}
+
+impl<StreamT> SubStream<StreamT> {
+    /// Absolute position of the window in the parent
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Size of the window in bytes
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Bytes left between the position and the end of the window
+    pub fn remaining(&self) -> u64 {
+        self.length.saturating_sub(self.position)
+    }
+
+    pub fn get_ref(&self) -> &StreamT {
+        &self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut StreamT {
+        &mut self.stream
+    }
+
+    pub fn into_inner(self) -> StreamT {
+        self.stream
+    }
+}
+
+impl<StreamT: Read + Seek> Read for SubStream<StreamT> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = usize::try_from(self.remaining())
+            .unwrap_or(usize::MAX)
+            .min(buf.len());
+        if length == 0 {
+            return Ok(0);
+        }
+
+        self.seek_parent()?;
+        let read = self.stream.read(&mut buf[..length])?;
+        self.position += read as u64;
+
+        Ok(read)
+    }
+}
+
+/// Like a fixed size buffer, a write that crosses the end of the window only writes
+/// the bytes that fit, and one at the end writes nothing, so nothing outside of the
+/// window is ever touched. `write_all` reports the latter as `ErrorKind::WriteZero`.
+impl<StreamT: Write + Seek> Write for SubStream<StreamT> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let length = usize::try_from(self.remaining())
+            .unwrap_or(usize::MAX)
+            .min(buf.len());
+        if length == 0 {
+            return Ok(0);
+        }
+
+        self.seek_parent()?;
+        let written = self.stream.write(&buf[..length])?;
+        self.position += written as u64;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+impl<StreamT: Seek> Seek for SubStream<StreamT> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let target = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.length.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+
+        // The absolute position in the parent has to be representable as well
+        self.position = target
+            .filter(|position| position.checked_add(self.offset).is_some())
+            .ok_or(Error::new(
+                ErrorKind::InvalidInput,
+                "Invalid seek to a negative or overflowing position.",
+            ))?;
+
+        Ok(self.position)
+    }
+}
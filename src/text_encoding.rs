@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+use std::io::{Error, ErrorKind};
+
+/// Character encoding of byte oriented strings.
+///
+/// Wide encodings (UTF-16/UTF-32) have their own readers and writers since their
+/// code units and terminators are wider than a byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    #[default]
+    Utf8,
+    /// ISO-8859-1, every byte maps to the code point of the same value
+    Latin1,
+    Windows1252,
+    ShiftJis,
+    EucKr,
+}
+
+impl Encoding {
+    /// Decode `bytes` into a string
+    ///
+    /// # Parameters
+    ///     `bytes` - The encoded text
+    ///     `lossy` - Replace invalid sequences with U+FFFD instead of failing
+    ///
+    /// # Errors
+    ///     io::Error::InvalidData - `bytes` is not valid in this encoding and `lossy` is not set
+    pub fn decode(self, bytes: Vec<u8>, lossy: bool) -> Result<String, Error> {
+        let decoded: Option<String> = match self {
+            Encoding::Utf8 if lossy => Some(String::from_utf8_lossy(&bytes).into_owned()),
+            Encoding::Utf8 => String::from_utf8(bytes).ok(),
+            Encoding::Latin1 => Some(bytes.iter().map(|byte| char::from(*byte)).collect()),
+            Encoding::Windows1252 => decode_with(encoding_rs::WINDOWS_1252, &bytes, lossy),
+            Encoding::ShiftJis => decode_with(encoding_rs::SHIFT_JIS, &bytes, lossy),
+            Encoding::EucKr => decode_with(encoding_rs::EUC_KR, &bytes, lossy),
+        };
+
+        decoded.ok_or(Error::new(
+            ErrorKind::InvalidData,
+            "Unable to convert bytes to string.",
+        ))
+    }
+
+    /// Encode `value` so that decoding the result gives back exactly `value`.
+    /// Characters the encoding cannot represent are an error rather than being
+    /// replaced, so edited text is never silently corrupted.
+    ///
+    /// # Errors
+    ///     io::Error::InvalidInput - `value` can not be represented exactly in this encoding
+    pub fn encode(self, value: &str) -> Result<Cow<'_, [u8]>, Error> {
+        let unrepresentable = || {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "String can not be represented in the target encoding.",
+            )
+        };
+
+        // UTF-8 and Latin-1 map one-to-one, the codecs are checked in `encode_with`
+        let encoded: Cow<[u8]> = match self {
+            Encoding::Utf8 => Cow::Borrowed(value.as_bytes()),
+            Encoding::Latin1 => Cow::Owned(
+                value
+                    .chars()
+                    .map(|character| u8::try_from(character).ok())
+                    .collect::<Option<Vec<u8>>>()
+                    .ok_or_else(unrepresentable)?,
+            ),
+            Encoding::Windows1252 => encode_with(encoding_rs::WINDOWS_1252, value)?,
+            Encoding::ShiftJis => encode_with(encoding_rs::SHIFT_JIS, value)?,
+            Encoding::EucKr => encode_with(encoding_rs::EUC_KR, value)?,
+        };
+
+        Ok(encoded)
+    }
+}
+
+fn decode_with(codec: &'static encoding_rs::Encoding, bytes: &[u8], lossy: bool) -> Option<String> {
+    if lossy {
+        return Some(codec.decode_without_bom_handling(bytes).0.into_owned());
+    }
+
+    codec
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(Cow::into_owned)
+}
+
+fn encode_with<'a>(
+    codec: &'static encoding_rs::Encoding,
+    value: &'a str,
+) -> Result<Cow<'a, [u8]>, Error> {
+    let (encoded, _, unmappable) = codec.encode(value);
+
+    // Guard against many-to-one mappings that would change the text on re-read
+    let round_trip = codec.decode_without_bom_handling_and_without_replacement(&encoded);
+    if unmappable || round_trip.as_deref() != Some(value) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "String can not be represented in the target encoding.",
+        ));
+    }
+
+    Ok(encoded)
+}